@@ -10,13 +10,82 @@ mod reportes {
     use ink::prelude::format;
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
-    use sistema_votacion::candidato::Candidato;
     use sistema_votacion::eleccion::Miembro;
     use sistema_votacion::enums::Error;
     use sistema_votacion::enums::EstadoDeEleccion;
     use sistema_votacion::usuario::*;
     use sistema_votacion::votante::Votante;
 
+    /// Ganador de una elección finalizada según los votos de sus candidatos.
+    ///
+    /// - `Ganador` contiene el nombre y apellido del candidato más votado y sus votos.
+    /// - `Empate` contiene los nombres de los candidatos empatados en la mayor cantidad
+    /// de votos y dicha cantidad.
+    /// - `SinVotos` si ningún candidato recibió votos, o la elección no tiene candidatos.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq)]
+    pub enum ResultadoGanador {
+        Ganador(String, u32),
+        Empate(Vec<String>, u32),
+        SinVotos,
+    }
+
+    /// Ganadores de una elección finalizada con uno o más escaños.
+    ///
+    /// - `ganadores` contiene los votos y el nombre y apellido de los candidatos que ocupan
+    /// los escaños, ordenados de manera descendente en cantidad de votos.
+    /// - `empate_en_corte` indica si hubo un empate en el último escaño, en cuyo caso se
+    /// incluyen todos los candidatos empatados y `ganadores` puede exceder los escaños.
+    ///
+    /// Los candidatos sin votos no ocupan escaños, por lo que `ganadores` puede tener menos
+    /// elementos que los escaños, o ninguno si nadie recibió votos.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq)]
+    pub struct ReporteGanadores {
+        pub ganadores: Vec<(u32, String)>,
+        pub empate_en_corte: bool,
+    }
+
+    /// Votante aprobado de una elección, identificado por su `AccountId`
+    /// junto a su nombre y apellido.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq)]
+    pub struct ReporteVotantes {
+        pub id: AccountId,
+        pub nombre: String,
+        pub apellido: String,
+    }
+
+    /// Participación en una elección:
+    ///
+    /// - `votaron` es la cantidad de votantes aprobados que emitieron su voto, incluyendo
+    /// los votos en blanco.
+    /// - `total_votantes` es la cantidad de votantes aprobados.
+    /// - `porcentaje` es el porcentaje de participación expresado en décimas, igual al
+    /// informado por `reporte_participacion`.
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq)]
+    pub struct ReporteParticipacion {
+        pub votaron: u32,
+        pub total_votantes: u32,
+        pub porcentaje: u16,
+    }
+
+    impl ReporteParticipacion {
+        /// Retorna la cantidad de votantes aprobados que no emitieron su voto.
+        /// `calcular_participacion` garantiza que `votaron` no supera a `total_votantes`.
+        fn abstenciones(&self) -> u32 {
+            self.total_votantes - self.votaron
+        }
+    }
+
+    /// Almacena el hash y el `AccountId` del contrato de votación.
+    ///
+    /// Los mensajes del contrato de votación se invocan por su selector, construido
+    /// a partir del nombre del mensaje, por lo que deben coincidir exactamente con los
+    /// definidos en `SistemaVotacion`: `get_hash`, `get_estado_eleccion`,
+    /// `get_votantes_aprobados`, `get_usuarios`, `get_resultado_final`, `get_quorum_alcanzado`,
+    /// `get_escaños` y `get_votos_en_blanco`.
     #[ink(storage)]
     pub struct Reportes {
         votacion_hash: Hash,
@@ -68,11 +137,32 @@ mod reportes {
                 .returns::<Result<EstadoDeEleccion, Error>>()
                 .invoke()
             {
-                Ok(estado) => match estado {
-                    EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
-                    EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
-                    EstadoDeEleccion::Finalizada => Ok(()),
-                },
+                Ok(estado) => estado.requiere_finalizada(),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// Indica si los resultados de la elección de id `id_eleccion` ya pueden consultarse,
+        /// es decir si esta finalizó, sin necesidad de interpretar el error de un reporte.
+        /// Una elección cancelada nunca tiene resultados disponibles.
+        /// Retorna `Error::VotacionNoExiste` si la elección no existe.
+        #[ink(message)]
+        pub fn resultados_disponibles(&self, id_eleccion: u32) -> Result<bool, Error> {
+            self.resultados_disponibles_interno(id_eleccion)
+        }
+
+        fn resultados_disponibles_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            match build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_estado_eleccion")))
+                        .push_arg(id_eleccion),
+                )
+                .returns::<Result<EstadoDeEleccion, Error>>()
+                .invoke()
+            {
+                Ok(estado) => Ok(estado == EstadoDeEleccion::Finalizada),
+                Err(Error::VotacionCancelada) => Ok(false),
                 Err(e) => Err(e),
             }
         }
@@ -89,6 +179,27 @@ mod reportes {
         }
 
         fn reporte_votantes_interno(&self, id_eleccion: u32) -> Result<Vec<String>, Error> {
+            Ok(self
+                .reporte_votantes_estructurado_interno(id_eleccion)?
+                .into_iter()
+                .map(|v| format!("{} {}", v.nombre, v.apellido))
+                .collect())
+        }
+
+        /// Igual a `reporte_votantes`, pero para cada votante aprobado retorna un
+        /// `ReporteVotantes` con su `AccountId`, nombre y apellido por separado.
+        #[ink(message)]
+        pub fn reporte_votantes_estructurado(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<ReporteVotantes>, Error> {
+            self.reporte_votantes_estructurado_interno(id_eleccion)
+        }
+
+        fn reporte_votantes_estructurado_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<ReporteVotantes>, Error> {
             match build_call::<DefaultEnvironment>()
                 .call(self.votacion_account_id)
                 .exec_input(
@@ -117,43 +228,69 @@ mod reportes {
                 .returns::<Result<Vec<Votante>, Error>>()
                 .invoke()?;
 
-            Ok(votantes_aprobados
+            votantes_aprobados
                 .iter()
                 .map(|v| {
-                    // Si nada nefasto está sucediendo, esto no debe fallar jamás
-                    match build_call::<DefaultEnvironment>()
-                        .call(self.votacion_account_id)
-                        .exec_input(
-                            ExecutionInput::new(Selector::new(ink::selector_bytes!(
-                                "get_usuarios"
-                            )))
-                            .push_arg(v.get_account_id()),
-                        )
-                        .returns::<Result<Usuario, Error>>()
-                        .invoke()
-                    {
-                        Ok(u) => format!("{} {}", u.nombre, u.apellido),
-                        Err(e) => panic!("{:?}", e),
-                    }
+                    let u = self.get_usuario(v.get_account_id())?;
+                    Ok(ReporteVotantes {
+                        id: v.get_account_id(),
+                        nombre: u.nombre,
+                        apellido: u.apellido,
+                    })
                 })
-                .collect())
+                .collect()
+        }
+
+        /// Obtiene la información del usuario `id` del contrato de votación.
+        /// Si el usuario no se encuentra registrado retorna `Error::UsuarioNoExistente`, de
+        /// forma que una inconsistencia en el contrato de votación no revierta el reporte.
+        fn get_usuario(&self, id: AccountId) -> Result<Usuario, Error> {
+            build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_usuarios")))
+                        .push_arg(id),
+                )
+                .returns::<Result<Usuario, Error>>()
+                .invoke()
         }
 
         /// El reporte de participación retorna para una elección de id `id_elección`
-        /// un `Result<(u32, u8), Error>`:
+        /// un `Result<(u32, u16), Error>`:
         ///
         /// - El primer campo es la cantidad de votantes
-        /// - El segundo campo es el porcentaje de participación, será siempre
-        /// un valor entre 0 y 100
+        /// - El segundo campo es el porcentaje de participación expresado en décimas,
+        /// será siempre un valor entre 0 y 1000 (por ejemplo, 667 representa un 66,7%).
+        /// Se redondea a la décima más cercana, y los valores intermedios hacia arriba.
+        /// Los votos en blanco cuentan para la participación
         ///
         /// Si bien los candidatos de una elección se consideran de carácter público, con
         /// fines de preservar la información personal solo se muestra el nombre y apellido.
         #[ink(message)]
-        pub fn reporte_participacion(&self, id_eleccion: u32) -> Result<(u32, u8), Error> {
+        pub fn reporte_participacion(&self, id_eleccion: u32) -> Result<(u32, u16), Error> {
             self.reporte_participacion_interno(id_eleccion)
         }
 
-        fn reporte_participacion_interno(&self, id_eleccion: u32) -> Result<(u32, u8), Error> {
+        fn reporte_participacion_interno(&self, id_eleccion: u32) -> Result<(u32, u16), Error> {
+            let reporte = self.reporte_participacion_estructurado_interno(id_eleccion)?;
+            Ok((reporte.total_votantes, reporte.porcentaje))
+        }
+
+        /// Igual a `reporte_participacion`, pero retorna un `ReporteParticipacion` con la
+        /// cantidad de votantes que votaron, el total de votantes y el porcentaje de
+        /// participación en campos con nombre.
+        #[ink(message)]
+        pub fn reporte_participacion_estructurado(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<ReporteParticipacion, Error> {
+            self.reporte_participacion_estructurado_interno(id_eleccion)
+        }
+
+        fn reporte_participacion_estructurado_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<ReporteParticipacion, Error> {
             self.get_estado_eleccion(id_eleccion)?;
             let votantes = build_call::<DefaultEnvironment>()
                 .call(self.votacion_account_id)
@@ -166,69 +303,505 @@ mod reportes {
                 .returns::<Result<Vec<Votante>, Error>>()
                 .invoke()?;
 
-            let cantidad_de_votantes = votantes.len() as u32;
-            let cantidad_de_votantes_que_votaron =
-                votantes.iter().fold(0, |acc, v| acc + v.get_votos());
+            calcular_participacion(&votantes)
+        }
 
-            // Atrapar error de división por cero
-            // Si no hay votantes, es seguro asumir que no hay votos
-            if cantidad_de_votantes == 0 {
-                Ok((0, 0))
-            } else {
-                // Es seguro hacer esta operación en un `u8`. Es imposible que hayan más
-                // votantes que votaron que votantes inscriptos en una elección
-                let porcentaje = cantidad_de_votantes_que_votaron * 100 / cantidad_de_votantes;
-                Ok((cantidad_de_votantes, porcentaje.try_into().unwrap()))
-            }
+        /// Reporta para una elección finalizada de id `id_eleccion` su participación en
+        /// relación a ambos roles, como
+        /// `(total_votantes_aprobados, total_candidatos_aprobados, votos_emitidos)`.
+        ///
+        /// Los votos emitidos incluyen los votos en blanco, igual que en `reporte_participacion`.
+        #[ink(message)]
+        pub fn reporte_participacion_detallada(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<(u32, u32, u32), Error> {
+            self.reporte_participacion_detallada_interno(id_eleccion)
+        }
+
+        fn reporte_participacion_detallada_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<(u32, u32, u32), Error> {
+            let participacion = self.reporte_participacion_estructurado_interno(id_eleccion)?;
+            let candidatos = build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "get_candidatos_aprobados_ids"
+                    )))
+                    .push_arg(id_eleccion),
+                )
+                .returns::<Result<Vec<AccountId>, Error>>()
+                .invoke()?;
+
+            Ok((participacion.total_votantes, candidatos.len() as u32, participacion.votaron))
+        }
+
+        /// Reporta para una elección de id `id_eleccion` la cantidad de votantes aprobados
+        /// que no emitieron su voto, solo cuando esta haya finalizado. Los votos en blanco
+        /// no se consideran abstenciones.
+        #[ink(message)]
+        pub fn reporte_abstenciones(&self, id_eleccion: u32) -> Result<u32, Error> {
+            self.reporte_abstenciones_interno(id_eleccion)
+        }
+
+        fn reporte_abstenciones_interno(&self, id_eleccion: u32) -> Result<u32, Error> {
+            let participacion = self.reporte_participacion_estructurado_interno(id_eleccion)?;
+            Ok(participacion.abstenciones())
+        }
+
+        /// Reporta para una elección finalizada de id `id_eleccion` un resumen de sus votos,
+        /// como `(total_votantes, votos_a_candidatos, votos_en_blanco, abstenciones)`.
+        ///
+        /// Cada votante aprobado se cuenta una única vez, sin considerar el peso de su voto,
+        /// por lo que `total_votantes` es la suma de los otros tres campos.
+        #[ink(message)]
+        pub fn reporte_resumen(&self, id_eleccion: u32) -> Result<(u32, u32, u32, u32), Error> {
+            self.reporte_resumen_interno(id_eleccion)
+        }
+
+        fn reporte_resumen_interno(&self, id_eleccion: u32) -> Result<(u32, u32, u32, u32), Error> {
+            let participacion = self.reporte_participacion_estructurado_interno(id_eleccion)?;
+            let votos_en_blanco = build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_votos_en_blanco")))
+                        .push_arg(id_eleccion),
+                )
+                .returns::<Result<u32, Error>>()
+                .invoke()?;
+
+            Ok((
+                participacion.total_votantes,
+                participacion.votaron.saturating_sub(votos_en_blanco),
+                votos_en_blanco,
+                participacion.abstenciones(),
+            ))
         }
 
         /// Reporta el resultado para un elección de id `id_elección`. Retorna un
-        /// `Result<Vec<(u32, Usuario)>, Error>`. Para cada elemento del arreglo:
+        /// `Result<Vec<(u32, String)>, Error>`. Para cada elemento del arreglo:
         ///
-        /// - El primer campo (`u32`) representa los votos del candidato, cuya información se
-        /// encuentra en el siguiente campo
-        /// - El segundo campo de tipo `Usuario` es la información del candidato.
+        /// - El primer campo (`u32`) representa los votos del candidato, considerando el peso
+        /// del voto de cada votante, cuya información se encuentra en el siguiente campo
+        /// - El segundo campo es el nombre y apellido del candidato.
         ///
         /// El arreglo se encuentra ordenado de manera descendente en cantidad de votos.
+        /// Los candidatos con igual cantidad de votos se ordenan por su `AccountId`,
+        /// de forma que el orden sea siempre el mismo.
+        ///
+        /// `get_resultado_final` retorna el `AccountId` y los votos de los candidatos aprobados,
+        /// por lo que el nombre de cada uno se obtiene mediante `get_usuarios`.
         #[ink(message)]
         pub fn reporte_resultado(&self, id_eleccion: u32) -> Result<Vec<(u32, String)>, Error> {
             self.reporte_resultado_interno(id_eleccion)
         }
 
         fn reporte_resultado_interno(&self, id_eleccion: u32) -> Result<Vec<(u32, String)>, Error> {
+            self.reporte_resultado_pagina_interno(id_eleccion, 0, u32::MAX)
+        }
+
+        /// Igual a `reporte_resultado`, pero retorna a lo sumo `limit` candidatos comenzando
+        /// desde la posición `offset` del resultado ordenado. Si `offset` excede la cantidad
+        /// de candidatos se retorna un vector vacío.
+        #[ink(message)]
+        pub fn reporte_resultado_pagina(
+            &self,
+            id_eleccion: u32,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(u32, String)>, Error> {
+            self.reporte_resultado_pagina_interno(id_eleccion, offset, limit)
+        }
+
+        fn reporte_resultado_pagina_interno(
+            &self,
+            id_eleccion: u32,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(u32, String)>, Error> {
+            let candidatos = self.get_candidatos_ordenados(id_eleccion)?;
+
+            candidatos
+                .iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|(id, votos)| {
+                    // recupera info de cada candidato
+                    let u = self.get_usuario(*id)?;
+                    Ok((*votos, format!("{} {}", u.nombre, u.apellido)))
+                })
+                .collect()
+        }
+
+        /// Igual a `reporte_resultado`, pero retorna solo los primeros `n` candidatos del
+        /// resultado ordenado. Si `n` excede la cantidad de candidatos se retornan todos.
+        #[ink(message)]
+        pub fn reporte_resultado_top(
+            &self,
+            id_eleccion: u32,
+            n: u32,
+        ) -> Result<Vec<(u32, String)>, Error> {
+            self.reporte_resultado_top_interno(id_eleccion, n)
+        }
+
+        fn reporte_resultado_top_interno(
+            &self,
+            id_eleccion: u32,
+            n: u32,
+        ) -> Result<Vec<(u32, String)>, Error> {
+            self.reporte_resultado_pagina_interno(id_eleccion, 0, n)
+        }
+
+        /// Reporta para una elección de id `id_eleccion` el nombre y apellido de cada candidato
+        /// junto al porcentaje de los votos a candidatos que obtuvo, expresado en puntos
+        /// básicos (de 0 a 10000), con el mismo orden que `reporte_resultado`.
+        /// Si ningún candidato recibió votos, todos los porcentajes son cero.
+        #[ink(message)]
+        pub fn reporte_resultado_porcentaje(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(String, u16)>, Error> {
+            self.reporte_resultado_porcentaje_interno(id_eleccion)
+        }
+
+        fn reporte_resultado_porcentaje_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(String, u16)>, Error> {
+            let resultados = self.reporte_resultado_interno(id_eleccion)?;
+            // Se suma en `u64` para evitar desbordamientos con cantidades grandes
+            let total: u64 = resultados.iter().map(|(votos, _)| u64::from(*votos)).sum();
+
+            Ok(resultados
+                .into_iter()
+                .map(|(votos, nombre)| {
+                    let porcentaje = match total {
+                        0 => 0,
+                        _ => porcentaje_en_puntos_basicos(votos, total),
+                    };
+                    (nombre, porcentaje)
+                })
+                .collect())
+        }
+
+        /// Reporta el resultado para una elección de id `id_eleccion` identificando a cada
+        /// candidato por su `AccountId`, de forma que pueda verificarse contra la cadena.
+        /// Cada elemento contiene el `AccountId` del candidato y sus votos, con el mismo
+        /// orden que `reporte_resultado`.
+        #[ink(message)]
+        pub fn reporte_resultado_detallado(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            self.reporte_resultado_detallado_interno(id_eleccion)
+        }
+
+        fn reporte_resultado_detallado_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            self.get_candidatos_ordenados(id_eleccion)
+        }
+
+        /// Obtiene el `AccountId` y los votos de los candidatos aprobados de una elección
+        /// finalizada, ordenados de manera descendente en cantidad de votos y, ante igualdad,
+        /// por su `AccountId`.
+        fn get_candidatos_ordenados(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
             self.get_estado_eleccion(id_eleccion)?;
-            let candidatos = build_call::<DefaultEnvironment>()
+            let mut candidatos = build_call::<DefaultEnvironment>()
                 .call(self.votacion_account_id)
                 .exec_input(
-                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_candidatos")))
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_resultado_final")))
                         .push_arg(id_eleccion),
                 )
-                .returns::<Result<Vec<Candidato>, Error>>()
+                .returns::<Result<Vec<(AccountId, u32)>, Error>>()
                 .invoke()?;
 
-            let mut resultados = candidatos
-                .iter()
-                .map(|c| {
-                    // Si nada nefasto está sucediendo, esto no debe puede ser error jamás, por eso `unwrap`
-                    // recupera info de cada candidato
-                    let u = build_call::<DefaultEnvironment>()
-                        .call(self.votacion_account_id)
-                        .exec_input(
-                            ExecutionInput::new(Selector::new(ink::selector_bytes!(
-                                "get_usuarios"
-                            )))
-                            .push_arg(c.get_account_id()),
-                        )
-                        .returns::<Result<Usuario, Error>>()
-                        .invoke()
-                        .unwrap();
-                    (c.get_votos(), format!("{} {}", u.nombre, u.apellido))
+            candidatos.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+            Ok(candidatos)
+        }
+
+        /// Reporta el ganador de la elección de id `id_eleccion`, solo cuando esta haya
+        /// finalizado. Se calcula a partir del resultado de la elección, detectando
+        /// empates en la mayor cantidad de votos.
+        #[ink(message)]
+        pub fn reporte_ganador(&self, id_eleccion: u32) -> Result<ResultadoGanador, Error> {
+            self.reporte_ganador_interno(id_eleccion)
+        }
+
+        fn reporte_ganador_interno(&self, id_eleccion: u32) -> Result<ResultadoGanador, Error> {
+            let resultados = self.reporte_resultado_interno(id_eleccion)?;
+
+            // El resultado se encuentra ordenado de manera descendente en cantidad de votos
+            let maximo = match resultados.first() {
+                Some((votos, _)) if *votos > 0 => *votos,
+                _ => return Ok(ResultadoGanador::SinVotos),
+            };
+
+            let mut ganadores: Vec<String> = resultados
+                .into_iter()
+                .take_while(|(votos, _)| *votos == maximo)
+                .map(|(_, nombre)| nombre)
+                .collect();
+
+            if ganadores.len() == 1 {
+                Ok(ResultadoGanador::Ganador(ganadores.remove(0), maximo))
+            } else {
+                Ok(ResultadoGanador::Empate(ganadores, maximo))
+            }
+        }
+
+        /// Reporta los candidatos que ocupan los escaños de la elección de id `id_eleccion`,
+        /// solo cuando esta haya finalizado. Si hay un empate en el último escaño se incluyen
+        /// todos los candidatos empatados, indicándolo en `empate_en_corte`.
+        #[ink(message)]
+        pub fn reporte_ganadores(&self, id_eleccion: u32) -> Result<ReporteGanadores, Error> {
+            self.reporte_ganadores_interno(id_eleccion)
+        }
+
+        fn reporte_ganadores_interno(&self, id_eleccion: u32) -> Result<ReporteGanadores, Error> {
+            let resultados = self.reporte_resultado_interno(id_eleccion)?;
+            let escaños = build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("get_escaños")))
+                        .push_arg(id_eleccion),
+                )
+                .returns::<Result<u32, Error>>()
+                .invoke()?;
+
+            Ok(seleccionar_ganadores(resultados, escaños))
+        }
+
+        /// Reporta si la elección de id `id_eleccion` alcanzó su quorum mínimo de votos,
+        /// solo cuando esta haya finalizado. Si algo falla retorna un `Error`, en particular
+        /// `Error::VotacionCancelada` si la elección fue cancelada.
+        #[ink(message)]
+        pub fn reporte_quorum_alcanzado(&self, id_eleccion: u32) -> Result<bool, Error> {
+            self.reporte_quorum_alcanzado_interno(id_eleccion)
+        }
+
+        fn reporte_quorum_alcanzado_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            self.get_estado_eleccion(id_eleccion)?;
+            build_call::<DefaultEnvironment>()
+                .call(self.votacion_account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "get_quorum_alcanzado"
+                    )))
+                    .push_arg(id_eleccion),
+                )
+                .returns::<Result<bool, Error>>()
+                .invoke()
+        }
+    }
+
+    /// Calcula la participación a partir de los votantes aprobados de una elección.
+    /// Las cantidades se acumulan en `u64` y solo se convierten a `u32` al final.
+    /// Retorna `Error::OverflowAritmetico` si alguna cantidad no puede representarse.
+    fn calcular_participacion(votantes: &[Votante]) -> Result<ReporteParticipacion, Error> {
+        let cantidad_de_votantes = votantes.len() as u64;
+        let cantidad_de_votantes_que_votaron: u64 =
+            votantes.iter().map(|v| u64::from(v.get_votos())).sum();
+
+        // Atrapar error de división por cero
+        // Si no hay votantes, es seguro asumir que no hay votos
+        let porcentaje = if cantidad_de_votantes == 0 {
+            0
+        } else {
+            porcentaje_en_decimas(cantidad_de_votantes_que_votaron, cantidad_de_votantes)?
+        };
+        Ok(ReporteParticipacion {
+            votaron: u32::try_from(cantidad_de_votantes_que_votaron)
+                .map_err(|_| Error::OverflowAritmetico)?,
+            total_votantes: u32::try_from(cantidad_de_votantes)
+                .map_err(|_| Error::OverflowAritmetico)?,
+            porcentaje,
+        })
+    }
+
+    /// Calcula el porcentaje, expresado en décimas, que representa `parte` sobre `total`,
+    /// redondeando a la décima más cercana y los valores intermedios hacia arriba.
+    /// `total` debe ser mayor a cero.
+    /// Retorna `Error::OverflowAritmetico` si algún paso del cálculo desborda o si el
+    /// porcentaje supera el 100%, es decir si `parte` supera a `total`.
+    fn porcentaje_en_decimas(parte: u64, total: u64) -> Result<u16, Error> {
+        let decimas = parte
+            .checked_mul(2000)
+            .and_then(|p| p.checked_add(total))
+            .zip(total.checked_mul(2))
+            .and_then(|(p, t)| p.checked_div(t))
+            .ok_or(Error::OverflowAritmetico)?;
+        u16::try_from(decimas)
+            .ok()
+            .filter(|&d| d <= 1000)
+            .ok_or(Error::OverflowAritmetico)
+    }
+
+    /// Calcula el porcentaje, expresado en puntos básicos, que representa `parte` sobre
+    /// `total`, redondeando al punto más cercano y los valores intermedios hacia arriba.
+    /// `total` debe ser mayor a cero y `parte` no puede superarlo.
+    fn porcentaje_en_puntos_basicos(parte: u32, total: u64) -> u16 {
+        let puntos = (u64::from(parte) * 20000 + total) / (total * 2);
+        // Es seguro convertir a `u16`, el resultado nunca supera 10000
+        puntos as u16
+    }
+
+    /// Selecciona los ganadores de los `escaños` a partir de `resultados`, ordenado de manera
+    /// descendente en cantidad de votos. Los candidatos empatados con el último ganador
+    /// también se incluyen, indicando el empate en el corte. Los candidatos sin votos no
+    /// ocupan escaños, por lo que si nadie recibió votos no hay ganadores.
+    fn seleccionar_ganadores(resultados: Vec<(u32, String)>, escaños: u32) -> ReporteGanadores {
+        let resultados: Vec<(u32, String)> = resultados
+            .into_iter()
+            .take_while(|(votos, _)| *votos > 0)
+            .collect();
+        let corte = match resultados.get((escaños as usize).saturating_sub(1)) {
+            Some((votos, _)) if resultados.len() > escaños as usize => *votos,
+            _ => {
+                return ReporteGanadores {
+                    ganadores: resultados,
+                    empate_en_corte: false,
+                }
+            }
+        };
+
+        let ganadores: Vec<(u32, String)> = resultados
+            .into_iter()
+            .take_while(|(votos, _)| *votos >= corte)
+            .collect();
+        ReporteGanadores {
+            empate_en_corte: ganadores.len() > escaños as usize,
+            ganadores,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_porcentaje_en_decimas() {
+            // 33,33...% se redondea hacia abajo
+            assert_eq!(porcentaje_en_decimas(1, 3), Ok(333));
+            // 66,66...% se redondea hacia arriba
+            assert_eq!(porcentaje_en_decimas(2, 3), Ok(667));
+            assert_eq!(porcentaje_en_decimas(3, 3), Ok(1000));
+            assert_eq!(porcentaje_en_decimas(0, 3), Ok(0));
+            // 0,05% es un valor intermedio, se redondea hacia arriba
+            assert_eq!(porcentaje_en_decimas(1, 2000), Ok(1));
+            assert_eq!(
+                porcentaje_en_decimas(u64::from(u32::MAX), u64::from(u32::MAX)),
+                Ok(1000)
+            );
+            // Cantidades que exceden `u32`
+            assert_eq!(
+                porcentaje_en_decimas(u64::from(u32::MAX) * 2, u64::from(u32::MAX) * 4),
+                Ok(500)
+            );
+
+            // Un porcentaje mayor al 100% o un cálculo que desborda `u64` son errores
+            assert_eq!(porcentaje_en_decimas(4, 3), Err(Error::OverflowAritmetico));
+            assert_eq!(
+                porcentaje_en_decimas(u64::MAX, u64::MAX),
+                Err(Error::OverflowAritmetico)
+            );
+        }
+
+        #[test]
+        fn test_calcular_participacion() {
+            // Sin votantes la participación es nula
+            assert_eq!(
+                calcular_participacion(&[]),
+                Ok(ReporteParticipacion {
+                    votaron: 0,
+                    total_votantes: 0,
+                    porcentaje: 0,
+                })
+            );
+
+            // Cien mil votantes, de los cuales votaron dos de cada tres
+            let votantes: Vec<Votante> = (0..100_000u32)
+                .map(|i| {
+                    let mut id = [0; 32];
+                    id[..4].copy_from_slice(&i.to_le_bytes());
+                    let mut votante = Votante::new(AccountId::from(id));
+                    if i % 3 != 0 {
+                        votante.votar().unwrap();
+                    }
+                    votante
+                })
+                .collect();
+            assert_eq!(
+                calcular_participacion(&votantes),
+                Ok(ReporteParticipacion {
+                    votaron: 66_666,
+                    total_votantes: 100_000,
+                    porcentaje: 667,
                 })
-                .collect::<Vec<(u32, String)>>();
+            );
+            assert_eq!(calcular_participacion(&votantes).unwrap().abstenciones(), 33_334);
+        }
+
+        #[test]
+        fn test_porcentaje_en_puntos_basicos() {
+            // 66,666...% se redondea hacia arriba, 33,333...% hacia abajo
+            assert_eq!(porcentaje_en_puntos_basicos(2, 3), 6667);
+            assert_eq!(porcentaje_en_puntos_basicos(1, 3), 3333);
+            assert_eq!(porcentaje_en_puntos_basicos(3, 3), 10000);
+            assert_eq!(porcentaje_en_puntos_basicos(0, 3), 0);
+            // Totales que exceden `u32` al sumar los votos de varios candidatos
+            assert_eq!(
+                porcentaje_en_puntos_basicos(u32::MAX, u64::from(u32::MAX) * 2),
+                5000
+            );
+        }
+
+        #[test]
+        fn test_seleccionar_ganadores() {
+            let resultados = |votos: &[u32]| -> Vec<(u32, String)> {
+                votos
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (*v, format!("Candidato {}", i)))
+                    .collect()
+            };
+
+            // Tres escaños sin empate en el corte
+            let reporte = seleccionar_ganadores(resultados(&[7, 5, 4, 2, 1]), 3);
+            assert_eq!(reporte.ganadores, resultados(&[7, 5, 4]));
+            assert!(!reporte.empate_en_corte);
+
+            // Empates que no afectan el corte no se informan
+            let reporte = seleccionar_ganadores(resultados(&[5, 5, 4, 2, 2]), 3);
+            assert_eq!(reporte.ganadores, resultados(&[5, 5, 4]));
+            assert!(!reporte.empate_en_corte);
 
-            resultados.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+            // Tres escaños con empate en el corte, se incluyen todos los empatados
+            let reporte = seleccionar_ganadores(resultados(&[7, 5, 4, 4, 1]), 3);
+            assert_eq!(reporte.ganadores, resultados(&[7, 5, 4, 4]));
+            assert!(reporte.empate_en_corte);
 
-            Ok(resultados)
+            // Menos candidatos que escaños
+            let reporte = seleccionar_ganadores(resultados(&[3, 3]), 3);
+            assert_eq!(reporte.ganadores, resultados(&[3, 3]));
+            assert!(!reporte.empate_en_corte);
+
+            // Los candidatos sin votos no ocupan escaños ni generan empate en el corte
+            let reporte = seleccionar_ganadores(resultados(&[3, 0, 0]), 2);
+            assert_eq!(reporte.ganadores, resultados(&[3]));
+            assert!(!reporte.empate_en_corte);
+
+            // Sin votos no hay ganadores
+            let reporte = seleccionar_ganadores(resultados(&[0, 0, 0]), 2);
+            assert!(reporte.ganadores.is_empty());
+            assert!(!reporte.empate_en_corte);
         }
     }
 
@@ -281,6 +854,49 @@ mod reportes {
             Ok(())
         }
 
+        #[ink_e2e::test]
+        async fn probar_get_hash<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // Deploy de dos instancias del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Falló la instanciación del contrato de votación");
+            let votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+
+            let mut constructor_votacion2 = SistemaVotacionRef::new();
+            let contrato_votacion2 = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::bob(),
+                    &mut constructor_votacion2,
+                )
+                .submit()
+                .await
+                .expect("Falló la instanciación del contrato de votación");
+            let votacion_call_builder2 = contrato_votacion2.call_builder::<SistemaVotacion>();
+
+            // Ambas instancias comparten el mismo código, por lo que su hash es idéntico
+            let hash = client
+                .call(&ink_e2e::alice(), &votacion_call_builder.get_hash())
+                .dry_run()
+                .await?
+                .return_value();
+            let hash2 = client
+                .call(&ink_e2e::alice(), &votacion_call_builder2.get_hash())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(hash, hash2);
+
+            Ok(())
+        }
+
         #[ink_e2e::test]
         async fn probar_id<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
             // Deploy del contrato de votación
@@ -374,6 +990,8 @@ mod reportes {
                         1,
                         1,
                         1970,
+                        0,
+                        true,
                     ),
                 )
                 .submit()
@@ -440,6 +1058,8 @@ mod reportes {
                         fin.day().try_into().unwrap(),
                         fin.month().try_into().unwrap(),
                         fin.year().try_into().unwrap(),
+                        0,
+                        true,
                     ),
                 )
                 .submit()
@@ -701,6 +1321,8 @@ mod reportes {
                         fin.day().try_into().unwrap(),
                         fin.month().try_into().unwrap(),
                         fin.year().try_into().unwrap(),
+                        0,
+                        true,
                     ),
                 )
                 .submit()
@@ -861,6 +1483,7 @@ mod reportes {
                     &votacion_call_builder.votar(
                         eleccion_id,
                         ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        0,
                     ),
                 )
                 .submit()
@@ -873,6 +1496,7 @@ mod reportes {
                     &votacion_call_builder.votar(
                         eleccion_id,
                         ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        0,
                     ),
                 )
                 .submit()
@@ -935,23 +1559,134 @@ mod reportes {
                 vec!["Dave D".to_string(), "Ferdie F".to_string()]
             );
 
-            assert_eq!(reporte_participacion, (2, 100));
-
+            // El reporte estructurado coincide con el anterior, conservando el `AccountId`
+            let reporte_votantes_estructurado = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_votantes_estructurado(eleccion_id),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .unwrap();
             assert_eq!(
-                reporte_resultado,
+                reporte_votantes_estructurado,
                 vec![
-                    (2, format!("{} {}", "Charlie", "C")),
-                    (0, format!("{} {}", "Bob", "B"))
+                    ReporteVotantes {
+                        id: ink_e2e::account_id(ink_e2e::AccountKeyring::Dave),
+                        nombre: String::from("Dave"),
+                        apellido: String::from("D"),
+                    },
+                    ReporteVotantes {
+                        id: ink_e2e::account_id(ink_e2e::AccountKeyring::Ferdie),
+                        nombre: String::from("Ferdie"),
+                        apellido: String::from("F"),
+                    },
                 ]
             );
+            assert_eq!(
+                reporte_votantes_estructurado
+                    .iter()
+                    .map(|v| format!("{} {}", v.nombre, v.apellido))
+                    .collect::<Vec<String>>(),
+                reporte_votantes
+            );
 
-            Ok(())
-        }
+            assert_eq!(reporte_participacion, (2, 1000));
 
-        #[ink_e2e::test]
-        async fn reportes2<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
-            // Deploy del contrato de votación
-            let mut constructor_votacion = SistemaVotacionRef::new();
+            // El reporte estructurado coincide con el anterior
+            let reporte_participacion_estructurado = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_participacion_estructurado(eleccion_id),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .unwrap();
+            assert_eq!(
+                reporte_participacion_estructurado,
+                ReporteParticipacion {
+                    votaron: 2,
+                    total_votantes: 2,
+                    porcentaje: 1000,
+                }
+            );
+            assert_eq!(
+                (
+                    reporte_participacion_estructurado.total_votantes,
+                    reporte_participacion_estructurado.porcentaje
+                ),
+                reporte_participacion
+            );
+
+            assert_eq!(
+                reporte_resultado,
+                vec![
+                    (2, format!("{} {}", "Charlie", "C")),
+                    (0, format!("{} {}", "Bob", "B"))
+                ]
+            );
+
+            // El resultado detallado identifica a cada candidato por su `AccountId`
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_detallado(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![
+                    (ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie), 2),
+                    (ink_e2e::account_id(ink_e2e::AccountKeyring::Bob), 0)
+                ])
+            );
+
+            // Charlie gana la elección con todos los votos
+            assert_eq!(
+                client
+                    .call(&ink_e2e::alice(), &call_builder.reporte_ganador(eleccion_id))
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(ResultadoGanador::Ganador(format!("{} {}", "Charlie", "C"), 2))
+            );
+
+            // Todos los votantes votaron, no hay abstenciones
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_abstenciones(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(0)
+            );
+
+            // La elección no requiere quorum mínimo
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_quorum_alcanzado(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(true)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_empate<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
             let contrato_votacion = client
                 .instantiate(
                     "sistema_votacion",
@@ -994,21 +1729,6 @@ mod reportes {
                 .return_value()
                 .is_ok());
 
-            // Registrar a Eve
-            assert!(client
-                .call(
-                    &ink_e2e::eve(),
-                    &votacion_call_builder.registrar_usuario(
-                        "Eve".to_string(),
-                        "E".to_string(),
-                        "33333333".to_string(),
-                    ),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .is_ok());
-
             // Registrar a Dave
             assert!(client
                 .call(
@@ -1016,7 +1736,7 @@ mod reportes {
                     &votacion_call_builder.registrar_usuario(
                         "Dave".to_string(),
                         "D".to_string(),
-                        "44444444".to_string(),
+                        "33333333".to_string(),
                     ),
                 )
                 .submit()
@@ -1031,7 +1751,7 @@ mod reportes {
                     &votacion_call_builder.registrar_usuario(
                         "Ferdie".to_string(),
                         "F".to_string(),
-                        "55555555".to_string(),
+                        "44444444".to_string(),
                     ),
                 )
                 .submit()
@@ -1057,6 +1777,8 @@ mod reportes {
                         fin.day().try_into().unwrap(),
                         fin.month().try_into().unwrap(),
                         fin.year().try_into().unwrap(),
+                        0,
+                        true,
                     ),
                 )
                 .submit()
@@ -1086,17 +1808,6 @@ mod reportes {
                 .return_value()
                 .unwrap();
 
-            // Registrar en la elección a Eve como Votante
-            client
-                .call(
-                    &ink_e2e::eve(),
-                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .unwrap();
-
             // Registrar en la elección a Dave como Votante
             client
                 .call(
@@ -1162,20 +1873,6 @@ mod reportes {
                 .await?
                 .return_value()
                 .unwrap();
-            client
-                .call(
-                    &ink_e2e::alice(),
-                    &votacion_call_builder.cambiar_estado_aprobacion(
-                        eleccion_id,
-                        ink_e2e::account_id(ink_e2e::AccountKeyring::Eve),
-                        Rol::Votante,
-                        EstadoAprobacion::Aprobado,
-                    ),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .unwrap();
             client
                 .call(
                     &ink_e2e::alice(),
@@ -1235,25 +1932,14 @@ mod reportes {
                 thread::sleep(time::Duration::from_millis(100));
             }
 
-            // votar
+            // votar, Dave a Bob y Ferdie a Charlie
             client
                 .call(
                     &ink_e2e::dave(),
-                    &votacion_call_builder.votar(
-                        eleccion_id,
-                        ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
-                    ),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .unwrap();
-            client
-                .call(
-                    &ink_e2e::eve(),
                     &votacion_call_builder.votar(
                         eleccion_id,
                         ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        0,
                     ),
                 )
                 .submit()
@@ -1266,6 +1952,7 @@ mod reportes {
                     &votacion_call_builder.votar(
                         eleccion_id,
                         ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        0,
                     ),
                 )
                 .submit()
@@ -1292,61 +1979,78 @@ mod reportes {
                 thread::sleep(time::Duration::from_millis(100));
             }
 
-            // Generar reportes
-            let reporte_votantes = client
-                .call(
-                    &ink_e2e::alice(),
-                    &call_builder.reporte_votantes(eleccion_id),
-                )
-                .dry_run()
-                .await?
-                .return_value()
-                .unwrap();
+            // Generar reportes, Bob y Charlie empatan con un voto cada uno
+            assert_eq!(
+                client
+                    .call(&ink_e2e::alice(), &call_builder.reporte_ganador(eleccion_id))
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(ResultadoGanador::Empate(
+                    vec![
+                        format!("{} {}", "Bob", "B"),
+                        format!("{} {}", "Charlie", "C")
+                    ],
+                    1
+                ))
+            );
 
-            let reporte_participacion = client
-                .call(
-                    &ink_e2e::alice(),
-                    &call_builder.reporte_participacion(eleccion_id),
-                )
-                .dry_run()
-                .await?
-                .return_value()
-                .unwrap();
+            // Los candidatos empatados se ordenan según su `AccountId`
+            let mut empatados = vec![
+                (
+                    ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                    format!("{} {}", "Bob", "B"),
+                ),
+                (
+                    ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                    format!("{} {}", "Charlie", "C"),
+                ),
+            ];
+            empatados.sort_by(|a, b| a.0.cmp(&b.0));
+            let esperado: Vec<(u32, String)> =
+                empatados.into_iter().map(|(_, nombre)| (1, nombre)).collect();
 
-            let reporte_resultado = client
-                .call(
-                    &ink_e2e::alice(),
-                    &call_builder.reporte_resultado(eleccion_id),
-                )
-                .dry_run()
-                .await?
-                .return_value()
-                .unwrap();
+            // El orden se mantiene entre distintas llamadas
+            for _ in 0..3 {
+                assert_eq!(
+                    client
+                        .call(&ink_e2e::alice(), &call_builder.reporte_resultado(eleccion_id))
+                        .dry_run()
+                        .await?
+                        .return_value(),
+                    Ok(esperado.clone())
+                );
+            }
 
+            // Paginado del resultado, de a un candidato
             assert_eq!(
-                reporte_votantes,
-                vec![
-                    "Dave D".to_string(),
-                    "Eve E".to_string(),
-                    "Ferdie F".to_string()
-                ]
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_pagina(eleccion_id, 1, 1)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(esperado[1..].to_vec())
             );
-
-            assert_eq!(reporte_participacion, (3, 100));
-
             assert_eq!(
-                reporte_resultado,
-                vec![
-                    (2, format!("{} {}", "Charlie", "C")),
-                    (1, format!("{} {}", "Bob", "B"))
-                ]
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_pagina(eleccion_id, 2, 1)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![])
             );
 
             Ok(())
         }
 
         #[ink_e2e::test]
-        async fn reportes_eleccion_vacia<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        async fn reportes2<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
             // Deploy del contrato de votación
             let mut constructor_votacion = SistemaVotacionRef::new();
             let contrato_votacion = client
@@ -1360,148 +2064,45 @@ mod reportes {
                 .expect("Fallo la instanciación del contrato de votación");
             let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
             let votacion_acc_id = contrato_votacion.account_id;
-            let votacion_hash = client
-                .call(&ink_e2e::bob(), &votacion_call_builder.get_hash())
-                .submit()
-                .await?
-                .return_value();
 
-            // Deploy y construción del contrato de reportes
-            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
-            let contrato_reportes = client
-                .instantiate(
-                    "contrato_reportes",
-                    &ink_e2e::alice(),
-                    &mut constructor_reportes,
+            // Registrar a bob
+            assert!(client
+                .call(
+                    &ink_e2e::bob(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Bob".to_string(),
+                        "B".to_string(),
+                        "11111111".to_string(),
+                    ),
                 )
                 .submit()
-                .await
-                .expect("Fallo la instanciación del contrato de reportes");
-            let call_builder = contrato_reportes.call_builder::<Reportes>();
+                .await?
+                .return_value()
+                .is_ok());
 
-            // Delegar el id de reportes en el contrato de votación
-            client
+            // Registrar a Charlie
+            assert!(client
                 .call(
-                    &ink_e2e::alice(),
-                    &votacion_call_builder
-                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                    &ink_e2e::charlie(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Charlie".to_string(),
+                        "C".to_string(),
+                        "22222222".to_string(),
+                    ),
                 )
                 .submit()
                 .await?
                 .return_value()
-                .unwrap();
+                .is_ok());
 
-            // Crear una elección finalizada y vacía
-            let inicio = Utc::now() - Duration::hours(24);
-            let fin = Utc::now() - Duration::hours(23);
-            let eleccion_id: u32 = client
+            // Registrar a Eve
+            assert!(client
                 .call(
-                    &ink_e2e::alice(),
-                    &votacion_call_builder.crear_eleccion(
-                        String::from("Presidente"),
-                        inicio.hour().try_into().unwrap(),
-                        inicio.minute().try_into().unwrap(),
-                        inicio.day().try_into().unwrap(),
-                        inicio.month().try_into().unwrap(),
-                        inicio.year().try_into().unwrap(),
-                        fin.hour().try_into().unwrap(),
-                        fin.minute().try_into().unwrap(),
-                        fin.day().try_into().unwrap(),
-                        fin.month().try_into().unwrap(),
-                        fin.year().try_into().unwrap(),
-                    ),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .unwrap();
-
-            // Probar reportes en una elección vacía
-            assert_eq!(
-                client
-                    .call(
-                        &ink_e2e::alice(),
-                        &call_builder.reporte_votantes(eleccion_id),
-                    )
-                    .dry_run()
-                    .await?
-                    .return_value(),
-                Ok(vec![])
-            );
-
-            assert_eq!(
-                client
-                    .call(
-                        &ink_e2e::alice(),
-                        &call_builder.reporte_participacion(eleccion_id),
-                    )
-                    .dry_run()
-                    .await?
-                    .return_value(),
-                Ok((0, 0))
-            );
-
-            assert_eq!(
-                client
-                    .call(
-                        &ink_e2e::alice(),
-                        &call_builder.reporte_resultado(eleccion_id),
-                    )
-                    .dry_run()
-                    .await?
-                    .return_value(),
-                Ok(vec![])
-            );
-
-            Ok(())
-        }
-
-        #[ink_e2e::test]
-        async fn reportes_eleccion_sin_votos<Client: E2EBackend>(
-            mut client: Client,
-        ) -> E2EResult<()> {
-            // Deploy del contrato de votación
-            let mut constructor_votacion = SistemaVotacionRef::new();
-            let contrato_votacion = client
-                .instantiate(
-                    "sistema_votacion",
-                    &ink_e2e::alice(),
-                    &mut constructor_votacion,
-                )
-                .submit()
-                .await
-                .expect("Fallo la instanciación del contrato de votación");
-            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
-            let votacion_acc_id = contrato_votacion.account_id;
-            let votacion_hash = client
-                .call(&ink_e2e::bob(), &votacion_call_builder.get_hash())
-                .submit()
-                .await?
-                .return_value();
-
-            // Registrar a bob
-            assert!(client
-                .call(
-                    &ink_e2e::bob(),
-                    &votacion_call_builder.registrar_usuario(
-                        "Bob".to_string(),
-                        "B".to_string(),
-                        "11111111".to_string(),
-                    ),
-                )
-                .submit()
-                .await?
-                .return_value()
-                .is_ok());
-
-            // Registrar a Charlie
-            assert!(client
-                .call(
-                    &ink_e2e::charlie(),
-                    &votacion_call_builder.registrar_usuario(
-                        "Charlie".to_string(),
-                        "C".to_string(),
-                        "22222222".to_string(),
+                    &ink_e2e::eve(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Eve".to_string(),
+                        "E".to_string(),
+                        "33333333".to_string(),
                     ),
                 )
                 .submit()
@@ -1516,7 +2117,7 @@ mod reportes {
                     &votacion_call_builder.registrar_usuario(
                         "Dave".to_string(),
                         "D".to_string(),
-                        "33333333".to_string(),
+                        "44444444".to_string(),
                     ),
                 )
                 .submit()
@@ -1531,7 +2132,7 @@ mod reportes {
                     &votacion_call_builder.registrar_usuario(
                         "Ferdie".to_string(),
                         "F".to_string(),
-                        "44444444".to_string(),
+                        "55555555".to_string(),
                     ),
                 )
                 .submit()
@@ -1557,6 +2158,8 @@ mod reportes {
                         fin.day().try_into().unwrap(),
                         fin.month().try_into().unwrap(),
                         fin.year().try_into().unwrap(),
+                        0,
+                        true,
                     ),
                 )
                 .submit()
@@ -1586,6 +2189,17 @@ mod reportes {
                 .return_value()
                 .unwrap();
 
+            // Registrar en la elección a Eve como Votante
+            client
+                .call(
+                    &ink_e2e::eve(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
             // Registrar en la elección a Dave como Votante
             client
                 .call(
@@ -1651,6 +2265,20 @@ mod reportes {
                 .await?
                 .return_value()
                 .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Eve),
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
             client
                 .call(
                     &ink_e2e::alice(),
@@ -1679,7 +2307,7 @@ mod reportes {
                 .expect("Fallo la instanciación del contrato de reportes");
             let call_builder = contrato_reportes.call_builder::<Reportes>();
 
-            // Delegar el id de reportes en el contrato de votación
+            // delegar el id de reportes en el contrato de votación
             client
                 .call(
                     &ink_e2e::alice(),
@@ -1692,7 +2320,7 @@ mod reportes {
                 .unwrap();
 
             // Esperar
-            std::io::stdout().write_all(b"\nEsperando...\n")?;
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
             loop {
                 if client
                     .call(
@@ -1703,20 +2331,80 @@ mod reportes {
                     .await?
                     .return_value()
                     .unwrap()
-                    == EstadoDeEleccion::Finalizada
+                    != EstadoDeEleccion::Pendiente
                 {
                     break;
                 }
                 thread::sleep(time::Duration::from_millis(100));
             }
 
-            // Generar reportes de una elección sin votos
+            // votar
+            client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.votar(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        0,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::eve(),
+                    &votacion_call_builder.votar(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        0,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::ferdie(),
+                    &votacion_call_builder.votar(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        0,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::EnCurso
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Generar reportes
             let reporte_votantes = client
                 .call(
                     &ink_e2e::alice(),
                     &call_builder.reporte_votantes(eleccion_id),
                 )
-                .submit()
+                .dry_run()
                 .await?
                 .return_value()
                 .unwrap();
@@ -1741,13 +2429,2101 @@ mod reportes {
                 .return_value()
                 .unwrap();
 
-            assert_eq!(reporte_votantes[0], format!("{} {}", "Dave", "D"),);
-            assert_eq!(reporte_votantes[1], format!("{} {}", "Ferdie", "F"),);
+            assert_eq!(
+                reporte_votantes,
+                vec![
+                    "Dave D".to_string(),
+                    "Eve E".to_string(),
+                    "Ferdie F".to_string()
+                ]
+            );
 
-            assert_eq!(reporte_participacion, (2, 0));
+            assert_eq!(reporte_participacion, (3, 1000));
 
-            assert_eq!(reporte_resultado[0], (0, format!("{} {}", "Bob", "B")),);
-            assert_eq!(reporte_resultado[1], (0, format!("{} {}", "Charlie", "C")));
+            assert_eq!(
+                reporte_resultado,
+                vec![
+                    (2, format!("{} {}", "Charlie", "C")),
+                    (1, format!("{} {}", "Bob", "B"))
+                ]
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_eleccion_vacia<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+            let votacion_hash = client
+                .call(&ink_e2e::bob(), &votacion_call_builder.get_hash())
+                .submit()
+                .await?
+                .return_value();
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Crear una elección finalizada y vacía
+            let inicio = Utc::now() - Duration::hours(24);
+            let fin = Utc::now() - Duration::hours(23);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Probar reportes en una elección vacía
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_votantes(eleccion_id),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![])
+            );
+
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_participacion(eleccion_id),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok((0, 0))
+            );
+
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado(eleccion_id),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![])
+            );
+
+            assert_eq!(
+                client
+                    .call(&ink_e2e::alice(), &call_builder.reporte_ganador(eleccion_id))
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(ResultadoGanador::SinVotos)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_eleccion_sin_votos<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+            let votacion_hash = client
+                .call(&ink_e2e::bob(), &votacion_call_builder.get_hash())
+                .submit()
+                .await?
+                .return_value();
+
+            // Registrar a bob
+            assert!(client
+                .call(
+                    &ink_e2e::bob(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Bob".to_string(),
+                        "B".to_string(),
+                        "11111111".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Charlie
+            assert!(client
+                .call(
+                    &ink_e2e::charlie(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Charlie".to_string(),
+                        "C".to_string(),
+                        "22222222".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Dave
+            assert!(client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Dave".to_string(),
+                        "D".to_string(),
+                        "33333333".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Ferdie
+            assert!(client
+                .call(
+                    &ink_e2e::ferdie(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Ferdie".to_string(),
+                        "F".to_string(),
+                        "44444444".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Bob como candidato
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Candidato),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Charlie como candidato
+            client
+                .call(
+                    &ink_e2e::charlie(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Candidato),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Dave como Votante
+            client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Ferdie como Votante
+            client
+                .call(
+                    &ink_e2e::ferdie(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Aprobar a todos
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        Rol::Candidato,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        Rol::Candidato,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Dave),
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Ferdie),
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"\nEsperando...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Generar reportes de una elección sin votos
+            let reporte_votantes = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_votantes(eleccion_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            let reporte_participacion = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_participacion(eleccion_id),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .unwrap();
+
+            let reporte_resultado = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_resultado(eleccion_id),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .unwrap();
+
+            assert_eq!(reporte_votantes[0], format!("{} {}", "Dave", "D"),);
+            assert_eq!(reporte_votantes[1], format!("{} {}", "Ferdie", "F"),);
+
+            assert_eq!(reporte_participacion, (2, 0));
+
+            assert_eq!(reporte_resultado[0], (0, format!("{} {}", "Bob", "B")),);
+            assert_eq!(reporte_resultado[1], (0, format!("{} {}", "Charlie", "C")));
+
+            // Ningún candidato recibió votos, no hay ganador
+            assert_eq!(
+                client
+                    .call(&ink_e2e::alice(), &call_builder.reporte_ganador(eleccion_id))
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(ResultadoGanador::SinVotos)
+            );
+
+            // Ambos votantes se abstuvieron
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_abstenciones(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(2)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_abstenciones<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+            // Registrar a bob
+            assert!(client
+                .call(
+                    &ink_e2e::bob(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Bob".to_string(),
+                        "B".to_string(),
+                        "11111111".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Charlie
+            assert!(client
+                .call(
+                    &ink_e2e::charlie(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Charlie".to_string(),
+                        "C".to_string(),
+                        "22222222".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Dave
+            assert!(client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Dave".to_string(),
+                        "D".to_string(),
+                        "33333333".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Registrar a Ferdie
+            assert!(client
+                .call(
+                    &ink_e2e::ferdie(),
+                    &votacion_call_builder.registrar_usuario(
+                        "Ferdie".to_string(),
+                        "F".to_string(),
+                        "44444444".to_string(),
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .is_ok());
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Bob como candidato
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Candidato),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Charlie como candidato
+            client
+                .call(
+                    &ink_e2e::charlie(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Candidato),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Dave como Votante
+            client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Registrar en la elección a Ferdie como Votante
+            client
+                .call(
+                    &ink_e2e::ferdie(),
+                    &votacion_call_builder.registrar_en_eleccion(eleccion_id, Rol::Votante),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Aprobar a todos
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        Rol::Candidato,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie),
+                        Rol::Candidato,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Dave),
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.cambiar_estado_aprobacion(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Ferdie),
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Solo Dave vota, Ferdie se abstiene
+            client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.votar(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        0,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Aún no es posible generar el reporte
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_abstenciones(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_abstenciones(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(1)
+            );
+
+            // Uno de dos votantes votó
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_participacion(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok((2, 500))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_resultado_top<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Concejal"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Bob, Charlie, Dave, Eve y Ferdie son candidatos, One y Two votantes
+            let miembros = [
+                (
+                    ink_e2e::bob(),
+                    ink_e2e::AccountKeyring::Bob,
+                    "Bob",
+                    "11111111",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::charlie(),
+                    ink_e2e::AccountKeyring::Charlie,
+                    "Charlie",
+                    "22222222",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::dave(),
+                    ink_e2e::AccountKeyring::Dave,
+                    "Dave",
+                    "33333333",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::eve(),
+                    ink_e2e::AccountKeyring::Eve,
+                    "Eve",
+                    "44444444",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::ferdie(),
+                    ink_e2e::AccountKeyring::Ferdie,
+                    "Ferdie",
+                    "55555555",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::one(),
+                    ink_e2e::AccountKeyring::One,
+                    "One",
+                    "66666666",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::two(),
+                    ink_e2e::AccountKeyring::Two,
+                    "Two",
+                    "77777777",
+                    Rol::Votante,
+                ),
+            ];
+            for (cuenta, keyring, nombre, dni, rol) in miembros {
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_usuario(
+                            nombre.to_string(),
+                            nombre[..1].to_string(),
+                            dni.to_string(),
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_en_eleccion(eleccion_id, rol.clone()),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.cambiar_estado_aprobacion(
+                            eleccion_id,
+                            ink_e2e::account_id(keyring),
+                            rol,
+                            EstadoAprobacion::Aprobado,
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // El voto de One pesa 3, de forma que el resultado no presente empates
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.set_peso_votante(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::One),
+                        3,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // One vota a Dave y Two a Eve
+            for (votante, candidato) in [
+                (ink_e2e::one(), ink_e2e::AccountKeyring::Dave),
+                (ink_e2e::two(), ink_e2e::AccountKeyring::Eve),
+            ] {
+                client
+                    .call(
+                        &votante,
+                        &votacion_call_builder.votar(eleccion_id, ink_e2e::account_id(candidato), 0),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Solo se informan los dos candidatos más votados
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_top(eleccion_id, 2)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![(3, "Dave D".to_string()), (1, "Eve E".to_string())])
+            );
+
+            // Si `n` excede la cantidad de candidatos se informan todos
+            let resultado = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_resultado(eleccion_id),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(resultado.as_ref().map(|r| r.len()), Ok(5));
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_top(eleccion_id, 10)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                resultado
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_resultado_porcentaje<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear dos elecciones, en la segunda nadie votará
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let mut ids = Vec::new();
+            for _ in 0..2 {
+                ids.push(
+                    client
+                        .call(
+                            &ink_e2e::alice(),
+                            &votacion_call_builder.crear_eleccion(
+                                String::from("Presidente"),
+                                inicio.hour().try_into().unwrap(),
+                                inicio.minute().try_into().unwrap(),
+                                inicio.day().try_into().unwrap(),
+                                inicio.month().try_into().unwrap(),
+                                inicio.year().try_into().unwrap(),
+                                fin.hour().try_into().unwrap(),
+                                fin.minute().try_into().unwrap(),
+                                fin.day().try_into().unwrap(),
+                                fin.month().try_into().unwrap(),
+                                fin.year().try_into().unwrap(),
+                                0,
+                                true,
+                            ),
+                        )
+                        .submit()
+                        .await?
+                        .return_value()
+                        .unwrap(),
+                );
+            }
+
+            // Bob y Charlie son candidatos, Dave, Eve y Ferdie votantes en ambas elecciones
+            let miembros = [
+                (
+                    ink_e2e::bob(),
+                    ink_e2e::AccountKeyring::Bob,
+                    "Bob",
+                    "11111111",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::charlie(),
+                    ink_e2e::AccountKeyring::Charlie,
+                    "Charlie",
+                    "22222222",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::dave(),
+                    ink_e2e::AccountKeyring::Dave,
+                    "Dave",
+                    "33333333",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::eve(),
+                    ink_e2e::AccountKeyring::Eve,
+                    "Eve",
+                    "44444444",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::ferdie(),
+                    ink_e2e::AccountKeyring::Ferdie,
+                    "Ferdie",
+                    "55555555",
+                    Rol::Votante,
+                ),
+            ];
+            for (cuenta, keyring, nombre, dni, rol) in miembros {
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_usuario(
+                            nombre.to_string(),
+                            nombre[..1].to_string(),
+                            dni.to_string(),
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                for eleccion_id in ids.iter().copied() {
+                    client
+                        .call(
+                            &cuenta,
+                            &votacion_call_builder.registrar_en_eleccion(eleccion_id, rol.clone()),
+                        )
+                        .submit()
+                        .await?
+                        .return_value()
+                        .unwrap();
+                    client
+                        .call(
+                            &ink_e2e::alice(),
+                            &votacion_call_builder.cambiar_estado_aprobacion(
+                                eleccion_id,
+                                ink_e2e::account_id(keyring),
+                                rol.clone(),
+                                EstadoAprobacion::Aprobado,
+                            ),
+                        )
+                        .submit()
+                        .await?
+                        .return_value()
+                        .unwrap();
+                }
+            }
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(ids[0]),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // En la primera elección Dave y Eve votan a Bob, Ferdie a Charlie
+            for (votante, candidato) in [
+                (ink_e2e::dave(), ink_e2e::AccountKeyring::Bob),
+                (ink_e2e::eve(), ink_e2e::AccountKeyring::Bob),
+                (ink_e2e::ferdie(), ink_e2e::AccountKeyring::Charlie),
+            ] {
+                client
+                    .call(
+                        &votante,
+                        &votacion_call_builder.votar(ids[0], ink_e2e::account_id(candidato), 0),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(ids[0]),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Bob obtuvo dos de tres votos y Charlie uno
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado_porcentaje(ids[0])
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(vec![
+                    ("Bob B".to_string(), 6667),
+                    ("Charlie C".to_string(), 3333)
+                ])
+            );
+
+            // Sin votos todos los porcentajes son cero
+            let resultado = client
+                .call(
+                    &ink_e2e::alice(),
+                    &call_builder.reporte_resultado_porcentaje(ids[1]),
+                )
+                .dry_run()
+                .await?
+                .return_value()
+                .unwrap();
+            assert_eq!(resultado.len(), 2);
+            assert!(resultado.iter().all(|(_, porcentaje)| *porcentaje == 0));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_resumen<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Bob es candidato, Charlie, Dave, Eve y Ferdie votantes
+            let miembros = [
+                (
+                    ink_e2e::bob(),
+                    ink_e2e::AccountKeyring::Bob,
+                    "Bob",
+                    "11111111",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::charlie(),
+                    ink_e2e::AccountKeyring::Charlie,
+                    "Charlie",
+                    "22222222",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::dave(),
+                    ink_e2e::AccountKeyring::Dave,
+                    "Dave",
+                    "33333333",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::eve(),
+                    ink_e2e::AccountKeyring::Eve,
+                    "Eve",
+                    "44444444",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::ferdie(),
+                    ink_e2e::AccountKeyring::Ferdie,
+                    "Ferdie",
+                    "55555555",
+                    Rol::Votante,
+                ),
+            ];
+            for (cuenta, keyring, nombre, dni, rol) in miembros {
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_usuario(
+                            nombre.to_string(),
+                            nombre[..1].to_string(),
+                            dni.to_string(),
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.registrar_y_aprobar(
+                            eleccion_id,
+                            ink_e2e::account_id(keyring),
+                            rol,
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Charlie y Dave votan a Bob, Eve vota en blanco y Ferdie se abstiene
+            for votante in [ink_e2e::charlie(), ink_e2e::dave()] {
+                client
+                    .call(
+                        &votante,
+                        &votacion_call_builder.votar(
+                            eleccion_id,
+                            ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                            0,
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+            client
+                .call(
+                    &ink_e2e::eve(),
+                    &votacion_call_builder.votar_en_blanco(eleccion_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Aún no es posible generar el reporte
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resumen(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Cuatro votantes: dos votos a Bob, uno en blanco y una abstención
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resumen(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok((4, 2, 1, 1))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_usuario_inexistente<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Bob es candidato, Charlie votante
+            let miembros = [
+                (
+                    ink_e2e::bob(),
+                    ink_e2e::AccountKeyring::Bob,
+                    "Bob",
+                    "11111111",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::charlie(),
+                    ink_e2e::AccountKeyring::Charlie,
+                    "Charlie",
+                    "22222222",
+                    Rol::Votante,
+                ),
+            ];
+            for (cuenta, keyring, nombre, dni, rol) in miembros {
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_usuario(
+                            nombre.to_string(),
+                            nombre[..1].to_string(),
+                            dni.to_string(),
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.registrar_y_aprobar(
+                            eleccion_id,
+                            ink_e2e::account_id(keyring),
+                            rol,
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Se migran los registros de Bob y Charlie a otras cuentas, por lo que los miembros
+            // de la elección dejan de ser usuarios del sistema
+            for (viejo, nuevo) in [
+                (ink_e2e::AccountKeyring::Bob, ink_e2e::AccountKeyring::Eve),
+                (
+                    ink_e2e::AccountKeyring::Charlie,
+                    ink_e2e::AccountKeyring::Ferdie,
+                ),
+            ] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder
+                            .migrar_usuario(ink_e2e::account_id(viejo), ink_e2e::account_id(nuevo)),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Los reportes informan el error en lugar de revertirse
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_votantes(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::UsuarioNoExistente)
+            );
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_resultado(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::UsuarioNoExistente)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_participacion_detallada<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Bob y Charlie son candidatos, Dave, Eve y Ferdie votantes
+            let miembros = [
+                (
+                    ink_e2e::bob(),
+                    ink_e2e::AccountKeyring::Bob,
+                    "Bob",
+                    "11111111",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::charlie(),
+                    ink_e2e::AccountKeyring::Charlie,
+                    "Charlie",
+                    "22222222",
+                    Rol::Candidato,
+                ),
+                (
+                    ink_e2e::dave(),
+                    ink_e2e::AccountKeyring::Dave,
+                    "Dave",
+                    "33333333",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::eve(),
+                    ink_e2e::AccountKeyring::Eve,
+                    "Eve",
+                    "44444444",
+                    Rol::Votante,
+                ),
+                (
+                    ink_e2e::ferdie(),
+                    ink_e2e::AccountKeyring::Ferdie,
+                    "Ferdie",
+                    "55555555",
+                    Rol::Votante,
+                ),
+            ];
+            for (cuenta, keyring, nombre, dni, rol) in miembros {
+                client
+                    .call(
+                        &cuenta,
+                        &votacion_call_builder.registrar_usuario(
+                            nombre.to_string(),
+                            nombre[..1].to_string(),
+                            dni.to_string(),
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.registrar_y_aprobar(
+                            eleccion_id,
+                            ink_e2e::account_id(keyring),
+                            rol,
+                        ),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap();
+            }
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Dave vota a Bob, Eve vota en blanco y Ferdie se abstiene
+            client
+                .call(
+                    &ink_e2e::dave(),
+                    &votacion_call_builder.votar(
+                        eleccion_id,
+                        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+                        0,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+            client
+                .call(
+                    &ink_e2e::eve(),
+                    &votacion_call_builder.votar_en_blanco(eleccion_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Aún no es posible generar el reporte
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_participacion_detallada(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Tres votantes y dos candidatos aprobados, con un voto a Bob y uno en blanco
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.reporte_participacion_detallada(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok((3, 2, 2))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reportes_resultados_disponibles<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Deploy del contrato de votación
+            let mut constructor_votacion = SistemaVotacionRef::new();
+            let contrato_votacion = client
+                .instantiate(
+                    "sistema_votacion",
+                    &ink_e2e::alice(),
+                    &mut constructor_votacion,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de votación");
+            let mut votacion_call_builder = contrato_votacion.call_builder::<SistemaVotacion>();
+            let votacion_acc_id = contrato_votacion.account_id;
+
+            // Crear una elección
+            let inicio = Utc::now() + Duration::minutes(1);
+            let fin = Utc::now() + Duration::minutes(2);
+            let eleccion_id: u32 = client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder.crear_eleccion(
+                        String::from("Presidente"),
+                        inicio.hour().try_into().unwrap(),
+                        inicio.minute().try_into().unwrap(),
+                        inicio.day().try_into().unwrap(),
+                        inicio.month().try_into().unwrap(),
+                        inicio.year().try_into().unwrap(),
+                        fin.hour().try_into().unwrap(),
+                        fin.minute().try_into().unwrap(),
+                        fin.day().try_into().unwrap(),
+                        fin.month().try_into().unwrap(),
+                        fin.year().try_into().unwrap(),
+                        0,
+                        true,
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // Deploy y construción del contrato de reportes
+            let mut constructor_reportes = ReportesRef::new(votacion_acc_id);
+            let contrato_reportes = client
+                .instantiate(
+                    "contrato_reportes",
+                    &ink_e2e::alice(),
+                    &mut constructor_reportes,
+                )
+                .submit()
+                .await
+                .expect("Fallo la instanciación del contrato de reportes");
+            let call_builder = contrato_reportes.call_builder::<Reportes>();
+
+            // Delegar el id de reportes en el contrato de votación
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &votacion_call_builder
+                        .establecer_contrato_reportes(contrato_reportes.account_id),
+                )
+                .submit()
+                .await?
+                .return_value()
+                .unwrap();
+
+            // La elección no existe
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.resultados_disponibles(eleccion_id + 1)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // La elección aún no inició
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.resultados_disponibles(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(false)
+            );
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que comience la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    != EstadoDeEleccion::Pendiente
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // La elección está en curso
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.resultados_disponibles(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(false)
+            );
+
+            // Esperar
+            std::io::stdout().write_all(b"Esperando a que finalice la votacion...\n")?;
+            loop {
+                if client
+                    .call(
+                        &ink_e2e::alice(),
+                        &votacion_call_builder.consultar_estado(eleccion_id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value()
+                    .unwrap()
+                    == EstadoDeEleccion::Finalizada
+                {
+                    break;
+                }
+                thread::sleep(time::Duration::from_millis(100));
+            }
+
+            // Una vez finalizada los resultados están disponibles
+            assert_eq!(
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &call_builder.resultados_disponibles(eleccion_id)
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value(),
+                Ok(true)
+            );
 
             Ok(())
         }
@@ -1,22 +1,29 @@
+use ink::prelude::string::String;
 use ink::primitives::AccountId;
 
 use crate::{eleccion::Miembro, enums::Error};
 
 /// Representa un candidato de una eleccion determinada.
-/// Almacena su `AccountId` y cantidad de votos recibidos.
+/// Almacena su `AccountId`, cantidad de votos recibidos, su posición en la boleta,
+/// opcionalmente el partido al que pertenece y su propuesta, y si se incorporó
+/// mediante un voto write-in.
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 #[derive(Debug, PartialEq, Eq)]
 pub struct Candidato {
     id: AccountId,
     votos: u32,
+    pub(crate) partido: Option<String>,
+    pub(crate) propuesta: Option<String>,
+    pub(crate) orden: u32,
+    pub(crate) write_in: bool,
 }
 
 impl Miembro for Candidato {
-    /// Incrementa en uno la cantidad de votos recibidos
+    /// Incrementa en uno la cantidad de votos recibidos.
+    /// Retorna `Error::LimiteVotosAlcanzado` si la cantidad de votos no puede incrementarse
     fn votar(&mut self) -> Result<(), Error> {
-        self.votos += 1;
-        Ok(())
+        self.votar_con_peso(1)
     }
 
     fn get_account_id(&self) -> AccountId {
@@ -30,9 +37,42 @@ impl Miembro for Candidato {
 
 impl Candidato {
     /// Construye un nuevo candidato con el `AccountId` dado.
-    /// Inicializa con cero votos recibidos.
+    /// Inicializa con cero votos recibidos, orden cero y sin partido ni propuesta,
+    /// como candidato formal.
     pub fn new(id: AccountId) -> Self {
-        Self { id, votos: 0 }
+        Self {
+            id,
+            votos: 0,
+            partido: None,
+            propuesta: None,
+            orden: 0,
+            write_in: false,
+        }
+    }
+
+    /// Construye un candidato con la cantidad de votos dada, solo para pruebas
+    #[cfg(test)]
+    pub(crate) fn con_votos(id: AccountId, votos: u32) -> Self {
+        Self {
+            votos,
+            ..Self::new(id)
+        }
+    }
+
+    /// Incrementa la cantidad de votos recibidos según el `peso` del voto.
+    /// Retorna `Error::LimiteVotosAlcanzado` si la cantidad de votos no puede incrementarse
+    pub(crate) fn votar_con_peso(&mut self, peso: u32) -> Result<(), Error> {
+        self.votos = self
+            .votos
+            .checked_add(peso)
+            .ok_or(Error::LimiteVotosAlcanzado)?;
+        Ok(())
+    }
+
+    /// Decrementa la cantidad de votos recibidos según el `peso` del voto.
+    /// Utilizado cuando un votante cambia su voto a otro candidato
+    pub(crate) fn quitar_voto(&mut self, peso: u32) {
+        self.votos = self.votos.saturating_sub(peso);
     }
 }
 
@@ -45,6 +85,10 @@ mod tests {
         let candidato_id: [u8; 32] = [5; 32];
         let candidato = Candidato::new(AccountId::from(candidato_id));
         assert_eq!(candidato.votos, 0);
+        assert!(candidato.partido.is_none());
+        assert!(candidato.propuesta.is_none());
+        assert_eq!(candidato.orden, 0);
+        assert!(!candidato.write_in);
     }
 
     #[test]
@@ -54,4 +98,40 @@ mod tests {
         assert!(candidato.votar().is_ok());
         assert_eq!(candidato.votos, 1);
     }
+
+    #[test]
+    fn probar_votar_candidato_limite() {
+        let candidato_id: [u8; 32] = [5; 32];
+        let mut candidato = Candidato::con_votos(AccountId::from(candidato_id), u32::MAX);
+        assert_eq!(candidato.votar(), Err(Error::LimiteVotosAlcanzado));
+        assert_eq!(candidato.votos, u32::MAX);
+    }
+
+    #[test]
+    fn probar_quitar_voto_candidato() {
+        let candidato_id: [u8; 32] = [5; 32];
+        let mut candidato = Candidato::new(AccountId::from(candidato_id));
+        candidato.votar().unwrap();
+        candidato.quitar_voto(1);
+        assert_eq!(candidato.votos, 0);
+
+        // No es posible tener votos negativos
+        candidato.quitar_voto(1);
+        assert_eq!(candidato.votos, 0);
+    }
+
+    #[test]
+    fn probar_votar_con_peso_candidato() {
+        let candidato_id: [u8; 32] = [5; 32];
+        let mut candidato = Candidato::new(AccountId::from(candidato_id));
+        assert!(candidato.votar_con_peso(3).is_ok());
+        assert!(candidato.votar().is_ok());
+        assert_eq!(candidato.votos, 4);
+        candidato.quitar_voto(3);
+        assert_eq!(candidato.votos, 1);
+        assert_eq!(
+            candidato.votar_con_peso(u32::MAX),
+            Err(Error::LimiteVotosAlcanzado)
+        );
+    }
 }
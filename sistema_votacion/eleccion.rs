@@ -1,15 +1,31 @@
-use crate::enums::{Error, EstadoDeEleccion};
+use crate::enums::{Error, EstadoDeEleccion, EstadoMiembro};
+use core::fmt::Display;
 use crate::votante::Votante;
 use crate::{candidato::Candidato, fecha::Fecha};
 use ink::prelude::{string::String, vec::Vec};
 use ink::primitives::AccountId;
 
+/// Largo máximo, en bytes, de la descripción de una elección
+pub(crate) const LARGO_MAXIMO_DESCRIPCION: usize = 512;
+
 /// Eleccion:
 /// * Identificador
 /// * Fechas de inicio y cierre de votación
+/// * Fecha de cierre del registro de miembros, por defecto igual a la de inicio
 /// * Vector de `Votante` aprobado y pendiente
 /// * Vector de `Candidato` aprobado y pendiente
-/// * Puesto por el que se vota en la elección
+/// * Puesto por el que se vota en la elección y una descripción opcional
+/// * Cantidad mínima de votos para que el resultado sea válido
+/// * Si fue cancelada por el administrador
+/// * Momento de creación, en tiempo unix
+/// * Si los candidatos aprobados pueden emitir su voto
+/// * Si se permite votar a votantes aprobados que no son candidatos (write-in)
+/// * Margen de gracia para registrarse luego del inicio, en milisegundos
+/// * Cantidad de escaños a cubrir, 1 en elecciones de un único ganador
+/// * Cantidad de votos en blanco emitidos
+/// * Resultado final, calculado la primera vez que se consulta una vez finalizada
+/// * Si solo pueden registrarse los usuarios invitados, y la lista de invitados
+/// * Grupo opcional, que permite presentar juntas elecciones de distintos puestos
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 #[derive(Debug)]
@@ -20,18 +36,41 @@ pub(crate) struct Eleccion {
     pub(crate) candidatos_pendientes: Vec<Candidato>,
     pub(crate) candidatos_aprobados: Vec<Candidato>,
     puesto: String,
+    pub(crate) descripcion: String,
     pub inicio: Fecha,
     pub fin: Fecha,
+    pub(crate) fin_registro: Fecha,
+    pub(crate) quorum_minimo: u32,
+    pub(crate) cancelada: bool,
+    pub(crate) creada_en: u64,
+    pub(crate) candidatos_pueden_votar: bool,
+    pub(crate) permite_write_in: bool,
+    pub(crate) gracia_ms: u64,
+    pub(crate) escaños: u32,
+    pub(crate) votos_en_blanco: u32,
+    pub(crate) resultado_final: Option<Vec<(AccountId, u32)>>,
+    pub(crate) modo_invitacion: bool,
+    pub(crate) invitados: Vec<AccountId>,
+    pub(crate) grupo: Option<u32>,
 }
 
 /// Roles posibles de un usuario que se registra en el sistema
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Rol {
     Candidato,
     Votante,
 }
 
+impl Display for Rol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Rol::Candidato => write!(f, "Candidato"),
+            Rol::Votante => write!(f, "Votante"),
+        }
+    }
+}
+
 /// Introduce comportamiento común entre miembros de elecciones
 pub trait Miembro {
     fn votar(&mut self) -> Result<(), Error>;
@@ -40,8 +79,17 @@ pub trait Miembro {
 }
 
 impl Eleccion {
-    /// Construcción de una elección vacía
-    pub(crate) fn new(id: u32, puesto: String, inicio: Fecha, fin: Fecha) -> Self {
+    /// Construcción de una elección vacía, creada en el tiempo unix `creada_en`.
+    /// `candidatos_pueden_votar` indica si un candidato aprobado puede votar.
+    pub(crate) fn new(
+        id: u32,
+        puesto: String,
+        inicio: Fecha,
+        fin: Fecha,
+        quorum_minimo: u32,
+        creada_en: u64,
+        candidatos_pueden_votar: bool,
+    ) -> Self {
         Self {
             id,
             votantes_pendientes: Vec::new(),
@@ -49,11 +97,51 @@ impl Eleccion {
             candidatos_pendientes: Vec::new(),
             candidatos_aprobados: Vec::new(),
             puesto,
+            descripcion: String::new(),
+            fin_registro: inicio.clone(),
             inicio,
             fin,
+            quorum_minimo,
+            cancelada: false,
+            creada_en,
+            candidatos_pueden_votar,
+            permite_write_in: false,
+            gracia_ms: 0,
+            escaños: 1,
+            votos_en_blanco: 0,
+            resultado_final: None,
+            modo_invitacion: false,
+            invitados: Vec::new(),
+            grupo: None,
         }
     }
 
+    /// Retorna el puesto por el que se vota en la elección
+    pub fn get_puesto(&self) -> String {
+        self.puesto.clone()
+    }
+
+    /// Retorna la cantidad de votantes aprobados que emitieron su voto
+    pub fn get_cantidad_votos(&self) -> u32 {
+        self.votantes_aprobados.iter().map(|v| v.get_votos()).sum()
+    }
+
+    /// Retorna la cantidad de miembros de la elección en el orden
+    /// (votantes pendientes, votantes aprobados, candidatos pendientes, candidatos aprobados)
+    pub fn get_conteos(&self) -> (u32, u32, u32, u32) {
+        (
+            self.votantes_pendientes.len() as u32,
+            self.votantes_aprobados.len() as u32,
+            self.candidatos_pendientes.len() as u32,
+            self.candidatos_aprobados.len() as u32,
+        )
+    }
+
+    /// Retorna `true` si la cantidad de votos emitidos alcanza el quorum mínimo
+    pub fn quorum_alcanzado(&self) -> bool {
+        self.get_cantidad_votos() >= self.quorum_minimo
+    }
+
     /// Verifica estado de la eleccion
     ///
     /// * `EstadoDeEleccion::Pendiente` si aún no ha iniciado
@@ -69,18 +157,197 @@ impl Eleccion {
         }
     }
 
+    /// Cancela la elección. Solo es posible si aún no ha iniciado.
+    ///
+    /// Retorna `Error::VotacionCancelada` si ya fue cancelada previamente.
+    pub(crate) fn cancelar(&mut self, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.cancelada = true;
+                Ok(())
+            }
+        }
+    }
+
+    /// Reemplaza la fecha de cierre de la elección, solo si aún no ha iniciado ni fue cancelada.
+    /// Verifica que la nueva fecha de cierre sea posterior a la de inicio.
+    pub(crate) fn extender_fin(&mut self, fin: Fecha, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                if !self.inicio.es_anterior_a(&fin) {
+                    return Err(Error::FechaFinalizacionInvalida);
+                }
+                self.fin = fin;
+                Ok(())
+            }
+        }
+    }
+
+    /// Finaliza la elección en el momento `tiempo`, estableciéndolo como su fecha de cierre.
+    /// Solo es posible si se encuentra en curso y no fue cancelada.
+    ///
+    /// Retorna `Error::VotacionNoIniciada` si aún no ha iniciado.
+    pub(crate) fn finalizar_anticipadamente(&mut self, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::EnCurso => {
+                self.fin = Fecha::desde_tiempo_unix(tiempo);
+                Ok(())
+            }
+        }
+    }
+
+    /// Quita al usuario de los miembros pendientes de aprobación según su `Rol`, solo si la
+    /// elección aún no ha iniciado ni fue cancelada.
+    pub(crate) fn abandonar(
+        &mut self,
+        id: &AccountId,
+        rol: &Rol,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => self.rechazar_miembro(id, rol),
+        }
+    }
+
+    /// Devuelve a todos los miembros aprobados a la lista de pendientes de aprobación,
+    /// solo si la elección aún no ha iniciado ni fue cancelada.
+    pub(crate) fn resetear_aprobaciones(&mut self, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.votantes_pendientes.append(&mut self.votantes_aprobados);
+                self.candidatos_pendientes.append(&mut self.candidatos_aprobados);
+                Ok(())
+            }
+        }
+    }
+
+    /// Quita al miembro aprobado `id` según su `Rol`, solo si la elección aún no ha
+    /// iniciado ni fue cancelada.
+    ///
+    /// Retorna `Error::VotanteNoExistente` o `Error::CandidatoNoExistente` si no se
+    /// encuentra aprobado con ese rol.
+    pub(crate) fn revocar_aprobacion(
+        &mut self,
+        id: &AccountId,
+        rol: &Rol,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => match rol {
+                Rol::Candidato => {
+                    match self.candidatos_aprobados.iter().position(|c| c.get_account_id() == *id) {
+                        Some(i) => {
+                            self.candidatos_aprobados.remove(i);
+                            Ok(())
+                        }
+                        None => Err(Error::CandidatoNoExistente),
+                    }
+                }
+                Rol::Votante => match self.votantes_aprobados.iter().position(|v| v.id == *id) {
+                    Some(i) => {
+                        self.votantes_aprobados.remove(i);
+                        Ok(())
+                    }
+                    None => Err(Error::VotanteNoExistente),
+                },
+            },
+        }
+    }
+
+    /// Verifica que la elección, aún pendiente, cuente con al menos dos candidatos aprobados.
+    pub(crate) fn validar_lista(&self, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente if self.candidatos_aprobados.len() < 2 => {
+                Err(Error::CandidatosInsuficientes)
+            }
+            EstadoDeEleccion::Pendiente => Ok(()),
+        }
+    }
+
+    /// Retorna el `AccountId` de los dos candidatos aprobados más votados de la elección
+    /// finalizada, ordenados de manera descendente en cantidad de votos. Ante igualdad
+    /// de votos se prioriza el menor `AccountId`, de forma que el resultado sea siempre el mismo.
+    ///
+    /// Retorna `Error::CandidatosInsuficientes` si la elección tiene menos de dos candidatos.
+    pub(crate) fn get_finalistas(&self, tiempo: u64) -> Result<Vec<AccountId>, Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        self.consultar_estado(tiempo).requiere_finalizada()?;
+        if self.candidatos_aprobados.len() < 2 {
+            return Err(Error::CandidatosInsuficientes);
+        }
+        let mut candidatos: Vec<&Candidato> = self.candidatos_aprobados.iter().collect();
+        candidatos.sort_by(|a, b| {
+            b.get_votos()
+                .cmp(&a.get_votos())
+                .then(a.get_account_id().cmp(&b.get_account_id()))
+        });
+        Ok(candidatos
+            .iter()
+            .take(2)
+            .map(|c| c.get_account_id())
+            .collect())
+    }
+
     /// Agrega un usuario a la eleccion, según su `Rol`.
-    /// Verifica que la eleccion no se haya iniciado aun.
+    /// Verifica que la eleccion no se haya iniciado aun ni haya sido cancelada. Si el registro
+    /// cierra al inicio, se aceptan registros durante los `gracia_ms` posteriores al mismo.
+    ///
+    /// Retorna `Error::RegistroCerrado` si ya alcanzó la fecha de cierre del registro.
     pub(crate) fn añadir_miembro(
         &mut self,
         id: AccountId,
         rol: Rol,
         tiempo: u64,
     ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
         match self.consultar_estado(tiempo) {
-            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::EnCurso if !self.en_gracia_registro(tiempo) => {
+                Err(Error::VotacionEnCurso)
+            }
             EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
-            EstadoDeEleccion::Pendiente => {
+            EstadoDeEleccion::Pendiente if tiempo >= self.fin_registro.get_tiempo_unix() => {
+                Err(Error::RegistroCerrado)
+            }
+            EstadoDeEleccion::Pendiente | EstadoDeEleccion::EnCurso => {
                 match rol {
                     Rol::Candidato => {
                         self.candidatos_pendientes.push(Candidato::new(id));
@@ -94,6 +361,57 @@ impl Eleccion {
         }
     }
 
+    /// Verifica que el usuario `id` pueda registrarse en la elección con el `rol` dado.
+    /// Un mismo usuario puede ser votante y candidato a la vez solo si la elección permite
+    /// que los candidatos voten.
+    ///
+    /// Retorna `Error::UsuarioYaEsCandidato` o `Error::UsuarioYaEsVotante` si ya está
+    /// registrado con ese rol, o con el otro si la elección no permite ambos.
+    pub(crate) fn verificar_registro(&self, id: &AccountId, rol: &Rol) -> Result<(), Error> {
+        let es_votante = self
+            .votantes_pendientes
+            .iter()
+            .chain(self.votantes_aprobados.iter())
+            .any(|v| v.id == *id);
+        let es_candidato = self
+            .candidatos_pendientes
+            .iter()
+            .chain(self.candidatos_aprobados.iter())
+            .any(|c| c.get_account_id() == *id);
+        match rol {
+            Rol::Votante if es_votante => Err(Error::UsuarioYaEsVotante),
+            Rol::Candidato if es_candidato => Err(Error::UsuarioYaEsCandidato),
+            _ if self.candidatos_pueden_votar => Ok(()),
+            _ if es_candidato => Err(Error::UsuarioYaEsCandidato),
+            _ if es_votante => Err(Error::UsuarioYaEsVotante),
+            _ => Ok(()),
+        }
+    }
+
+    /// Retorna `true` si el registro cierra al inicio de la elección y `tiempo` se encuentra
+    /// dentro del margen de gracia posterior al mismo.
+    fn en_gracia_registro(&self, tiempo: u64) -> bool {
+        let inicio = self.inicio.get_tiempo_unix();
+        self.fin_registro.get_tiempo_unix() >= inicio
+            && tiempo < inicio.saturating_add(self.gracia_ms)
+    }
+
+    /// Verifica que los miembros pendientes aún puedan aprobarse o rechazarse: mientras la
+    /// elección no haya iniciado, o dentro del margen de gracia posterior a su inicio, de forma
+    /// que quienes se registran durante el mismo puedan votar.
+    pub(crate) fn permite_aprobacion(&self, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso if !self.en_gracia_registro(tiempo) => {
+                Err(Error::VotacionEnCurso)
+            }
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente | EstadoDeEleccion::EnCurso => Ok(()),
+        }
+    }
+
     /// Retorna `Some(usize)` con la posición del usuario pendiente de aprobación o `None` si
     /// este no se encuentra.
     pub fn get_posicion_miembro_pendiente(&self, id: &AccountId, rol: &Rol) -> Option<usize> {
@@ -137,24 +455,41 @@ impl Eleccion {
         }
     }
 
-    /// Retorna `true` si el usuario con `AccoundId` especificado existe en la eleccion,
-    /// sea `Candidato` o `Votante`
-    pub fn existe_usuario(&self, id: &AccountId) -> bool {
-        self.votantes_pendientes
+    /// Retorna el `Rol` con el que el usuario de `AccountId` especificado está registrado
+    /// en la elección, aprobado o pendiente de aprobación. `None` si no es miembro.
+    pub fn existe_usuario(&self, id: &AccountId) -> Option<Rol> {
+        if self
+            .votantes_pendientes
             .iter()
+            .chain(self.votantes_aprobados.iter())
             .any(|vot| vot.get_account_id() == *id)
-            || self
-                .votantes_aprobados
-                .iter()
-                .any(|vot| vot.get_account_id() == *id)
-            || self
-                .candidatos_pendientes
-                .iter()
-                .any(|cand| cand.get_account_id() == *id)
-            || self
-                .candidatos_aprobados
-                .iter()
-                .any(|cand| cand.get_account_id() == *id)
+        {
+            Some(Rol::Votante)
+        } else if self
+            .candidatos_pendientes
+            .iter()
+            .chain(self.candidatos_aprobados.iter())
+            .any(|cand| cand.get_account_id() == *id)
+        {
+            Some(Rol::Candidato)
+        } else {
+            None
+        }
+    }
+
+    /// Retorna el `EstadoMiembro` del usuario con `AccountId` especificado en la elección
+    pub fn get_estado_miembro(&self, id: &AccountId) -> EstadoMiembro {
+        if self.votantes_pendientes.iter().any(|v| v.get_account_id() == *id) {
+            EstadoMiembro::VotantePendiente
+        } else if self.votantes_aprobados.iter().any(|v| v.get_account_id() == *id) {
+            EstadoMiembro::VotanteAprobado
+        } else if self.candidatos_pendientes.iter().any(|c| c.get_account_id() == *id) {
+            EstadoMiembro::CandidatoPendiente
+        } else if self.candidatos_aprobados.iter().any(|c| c.get_account_id() == *id) {
+            EstadoMiembro::CandidatoAprobado
+        } else {
+            EstadoMiembro::NoRegistrado
+        }
     }
 
     /// Retorna `true` si el usuario con `AccountId` especificado es un miembro
@@ -169,9 +504,26 @@ impl Eleccion {
                 .any(|cand| cand.get_account_id() == *id)
     }
 
+    /// Retorna `true` solo si el usuario con `AccountId` especificado se encuentra entre los
+    /// candidatos aprobados y no quedó a su vez pendiente de aprobación como candidato.
+    fn es_candidato_aprobado(&self, id: &AccountId) -> bool {
+        self.candidatos_aprobados
+            .iter()
+            .any(|cand| cand.get_account_id() == *id)
+            && self.get_posicion_miembro_pendiente(id, &Rol::Candidato).is_none()
+    }
+
     /// Dado un `AccoundId` y `Rol`, aprueba al usuario. Retorna `Ok()` si se ha realizado
     /// de forma exitosa o `Error` si el usuario no se ha hallado.
+    /// Retorna `Error::MiembroYaAprobado` si el usuario ya fue aprobado con ese `Rol`.
     pub fn aprobar_miembro(&mut self, id: &AccountId, rol: &Rol) -> Result<(), Error> {
+        let ya_aprobado = match rol {
+            Rol::Candidato => self.candidatos_aprobados.iter().any(|c| c.get_account_id() == *id),
+            Rol::Votante => self.votantes_aprobados.iter().any(|v| v.id == *id),
+        };
+        if ya_aprobado {
+            return Err(Error::MiembroYaAprobado);
+        }
         if let Some(pos) = self.get_posicion_miembro_pendiente(id, rol) {
             match rol {
                 Rol::Candidato => {
@@ -240,73 +592,516 @@ impl Eleccion {
             .collect()
     }
 
+    /// Retorna el `AccountId`, partido y propuesta de cada candidato aprobado.
+    pub fn get_perfiles_candidatos(&self) -> Vec<(AccountId, Option<String>, Option<String>)> {
+        self.candidatos_aprobados
+            .iter()
+            .map(|c| (c.get_account_id(), c.partido.clone(), c.propuesta.clone()))
+            .collect()
+    }
+
+    /// Retorna el `AccountId` y los votos de cada candidato aprobado de la elección finalizada.
+    /// La primera consulta almacena el resultado, que se retorna sin recalcular en las siguientes
+    /// aunque los candidatos se modifiquen.
+    ///
+    /// Retorna `Error::VotacionNoIniciada` o `Error::VotacionEnCurso` si aún no finalizó.
+    pub(crate) fn get_resultado_final(
+        &mut self,
+        tiempo: u64,
+    ) -> Result<Vec<(AccountId, u32)>, Error> {
+        self.consultar_estado(tiempo).requiere_finalizada()?;
+        let resultado = self.resultado_final.get_or_insert_with(|| {
+            self.candidatos_aprobados
+                .iter()
+                .map(|c| (c.get_account_id(), c.get_votos()))
+                .collect()
+        });
+        Ok(resultado.clone())
+    }
+
+    /// Retorna el `AccountId` y el orden de cada candidato aprobado, según su posición en la
+    /// boleta. Los candidatos con el mismo orden conservan el orden en que fueron aprobados.
+    pub fn get_candidatos_ordenados(&self) -> Vec<(AccountId, u32)> {
+        let mut candidatos: Vec<(AccountId, u32)> = self
+            .candidatos_aprobados
+            .iter()
+            .map(|c| (c.get_account_id(), c.orden))
+            .collect();
+        candidatos.sort_by_key(|&(_, orden)| orden);
+        candidatos
+    }
+
+    /// Establece la posición `orden` en la boleta del candidato `id`, pendiente o aprobado,
+    /// solo si la elección aún no ha iniciado ni fue cancelada.
+    ///
+    /// Retorna `Error::CandidatoNoExistente` si `id` no es candidato de la elección.
+    pub(crate) fn set_orden_candidato(
+        &mut self,
+        id: &AccountId,
+        orden: u32,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                let candidato = self
+                    .candidatos_pendientes
+                    .iter_mut()
+                    .chain(self.candidatos_aprobados.iter_mut())
+                    .find(|c| c.get_account_id() == *id)
+                    .ok_or(Error::CandidatoNoExistente)?;
+                candidato.orden = orden;
+                Ok(())
+            }
+        }
+    }
+
+    /// Establece el `partido` y la `propuesta` del candidato `id`, pendiente o aprobado,
+    /// solo si la elección aún no ha iniciado ni fue cancelada.
+    ///
+    /// Retorna `Error::CandidatoNoExistente` si `id` no es candidato de la elección.
+    pub(crate) fn set_perfil_candidato(
+        &mut self,
+        id: &AccountId,
+        partido: Option<String>,
+        propuesta: Option<String>,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                let candidato = self
+                    .candidatos_pendientes
+                    .iter_mut()
+                    .chain(self.candidatos_aprobados.iter_mut())
+                    .find(|c| c.get_account_id() == *id)
+                    .ok_or(Error::CandidatoNoExistente)?;
+                candidato.partido = partido;
+                candidato.propuesta = propuesta;
+                Ok(())
+            }
+        }
+    }
+
+    /// Retorna `Error::CandidatoNoPuedeVotar` si `id_votante` es un candidato aprobado y la
+    /// elección no permite que los candidatos voten. Los candidatos incorporados mediante un
+    /// voto write-in conservan su voto, ya que se registraron como votantes.
+    fn verificar_candidato_puede_votar(&self, id_votante: &AccountId) -> Result<(), Error> {
+        if !self.candidatos_pueden_votar
+            && self
+                .candidatos_aprobados
+                .iter()
+                .any(|c| c.get_account_id() == *id_votante && !c.write_in)
+        {
+            return Err(Error::CandidatoNoPuedeVotar);
+        }
+        Ok(())
+    }
+
     /// Permite que el votante `id_votante` vote al candidato `id_cantidato`.
     /// Una vez que esto ocurre, el votante no puede volver a votar
+    ///
+    /// Retorna `Error::CandidatoNoPuedeVotar` si el votante es también un candidato aprobado
+    /// y la elección no permite que los candidatos voten.
     pub fn votar(
         &mut self,
         id_votante: AccountId,
         id_candidato: AccountId,
         tiempo: u64,
     ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
         return match self.consultar_estado(tiempo) {
             EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
             EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
             EstadoDeEleccion::EnCurso => {
                 // El código está raro con el fin no romper las reglas de ownership
-                if self
-                    .buscar_miembro_aprobado(&id_candidato, &Rol::Candidato)
-                    .is_none()
-                {
+                if !self.es_candidato_aprobado(&id_candidato) {
                     Err(Error::CandidatoNoExistente)
+                } else if let Err(e) = self.verificar_candidato_puede_votar(&id_votante) {
+                    Err(e)
                 } else if let Some(votante) =
-                    self.buscar_miembro_aprobado(&id_votante, &Rol::Votante)
+                    self.votantes_aprobados.iter_mut().find(|v| v.id == id_votante)
                 {
-                    votante.votar().map(|()| {
-                        self.buscar_miembro_aprobado(&id_candidato, &Rol::Candidato)
-                            .unwrap()
-                            .votar()
-                    })?
+                    votante.votar()?;
+                    votante.voto_a = Some(id_candidato);
+                    let peso = votante.peso;
+                    self.sumar_votos_candidato(&id_candidato, peso)
                 } else {
                     Err(Error::VotanteNoExistente)
                 }
             }
         };
     }
-}
 
-mod tests {
-    #![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
-    use crate::{
-        eleccion::{Eleccion, Miembro, Rol},
-        enums::{Error, EstadoDeEleccion},
-        fecha::Fecha,
-    };
-    use ink::primitives::AccountId;
+    /// Igual a `votar`, registrando además el `nonce` de la transacción del votante.
+    /// Si el votante ya votó con el mismo `nonce` se trata de un reenvío, por lo que retorna
+    /// `Ok(false)` sin registrar un nuevo voto. Retorna `Ok(true)` si el voto se registra.
+    pub fn votar_con_nonce(
+        &mut self,
+        id_votante: AccountId,
+        id_candidato: AccountId,
+        nonce: u64,
+        tiempo: u64,
+    ) -> Result<bool, Error> {
+        if self
+            .votantes_aprobados
+            .iter()
+            .any(|v| v.id == id_votante && v.ha_votado && v.nonce == Some(nonce))
+        {
+            return Ok(false);
+        }
+        self.votar(id_votante, id_candidato, tiempo)?;
+        if let Some(votante) = self.votantes_aprobados.iter_mut().find(|v| v.id == id_votante) {
+            votante.nonce = Some(nonce);
+        }
+        Ok(true)
+    }
 
-    #[test]
-    fn test_estado_eleccion() {
-        let id = 1;
-        let puesto = "Presidente".to_string();
-        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
-        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+    /// Permite que el votante `id_votante`, que ya votó, cambie su voto al candidato
+    /// `nuevo_candidato`. Se descuenta el voto al candidato elegido previamente, si lo hubiera.
+    ///
+    /// Retorna `Error::VotanteNoHaVotado` si el votante aún no ha votado.
+    /// Retorna `Error::CandidatoNoPuedeVotar` si el votante es también un candidato aprobado
+    /// y la elección no permite que los candidatos voten.
+    pub fn cambiar_voto(
+        &mut self,
+        id_votante: AccountId,
+        nuevo_candidato: AccountId,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::EnCurso => {
+                if !self.es_candidato_aprobado(&nuevo_candidato) {
+                    return Err(Error::CandidatoNoExistente);
+                }
+                self.verificar_candidato_puede_votar(&id_votante)?;
+
+                let Some(votante) = self.votantes_aprobados.iter_mut().find(|v| v.id == id_votante)
+                else {
+                    return Err(Error::VotanteNoExistente);
+                };
+                if !votante.ha_votado {
+                    return Err(Error::VotanteNoHaVotado);
+                }
+                let voto_anterior = votante.voto_a.replace(nuevo_candidato);
+                let peso = votante.peso;
+
+                match voto_anterior {
+                    Some(anterior) => {
+                        if let Some(c) = self
+                            .candidatos_aprobados
+                            .iter_mut()
+                            .find(|c| c.get_account_id() == anterior)
+                        {
+                            c.quitar_voto(peso);
+                        }
+                    }
+                    // Había votado en blanco
+                    None => self.votos_en_blanco = self.votos_en_blanco.saturating_sub(1),
+                }
+                self.sumar_votos_candidato(&nuevo_candidato, peso)
+            }
+        }
+    }
 
-        let eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+    /// Suma `peso` votos al candidato aprobado `id_candidato`.
+    fn sumar_votos_candidato(&mut self, id_candidato: &AccountId, peso: u32) -> Result<(), Error> {
+        match self
+            .candidatos_aprobados
+            .iter_mut()
+            .find(|c| c.get_account_id() == *id_candidato)
+        {
+            Some(c) => c.votar_con_peso(peso),
+            None => Err(Error::CandidatoNoExistente),
+        }
+    }
 
-        assert_eq!(
-            eleccion.consultar_estado(1716138000000), // 19/5/2024 17:00:00
-            EstadoDeEleccion::Pendiente
-        );
-        assert_eq!(
-            eleccion.consultar_estado(1716163199000), // 19/5/2024 23:59:59
-            EstadoDeEleccion::Pendiente
-        );
-        assert_eq!(
-            eleccion.consultar_estado(1716163200000), // 20/5/2024 00:00:00
-            EstadoDeEleccion::EnCurso
-        );
-        assert_eq!(
-            eleccion.consultar_estado(1716224400000), // 20/5/2024 17:00:00
-            EstadoDeEleccion::EnCurso
+    /// Habilita o deshabilita los votos write-in, solo si la elección aún no ha iniciado
+    /// ni fue cancelada.
+    pub(crate) fn set_permite_write_in(&mut self, permite: bool, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.permite_write_in = permite;
+                Ok(())
+            }
+        }
+    }
+
+    /// Habilita o deshabilita el modo por invitación, solo si la elección aún no ha iniciado
+    /// ni fue cancelada.
+    pub(crate) fn set_modo_invitacion(&mut self, activo: bool, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.modo_invitacion = activo;
+                Ok(())
+            }
+        }
+    }
+
+    /// Agrega a la lista de invitados las cuentas de `invitados` que aún no lo estén, solo si
+    /// la elección aún no ha iniciado ni fue cancelada.
+    pub(crate) fn invitar(&mut self, invitados: Vec<AccountId>, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                for id in invitados {
+                    if !self.invitados.contains(&id) {
+                        self.invitados.push(id);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Retorna true si `id` puede registrarse en la elección: siempre que no esté en modo por
+    /// invitación, o si fue invitado.
+    pub(crate) fn esta_invitado(&self, id: &AccountId) -> bool {
+        !self.modo_invitacion || self.invitados.contains(id)
+    }
+
+    /// Establece el `grupo` de la elección, o lo quita si es `None`, solo si la elección aún
+    /// no ha iniciado ni fue cancelada.
+    pub(crate) fn set_grupo(&mut self, grupo: Option<u32>, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.grupo = grupo;
+                Ok(())
+            }
+        }
+    }
+
+    /// Establece el margen de gracia `gracia_ms` para registrarse luego del inicio, solo si la
+    /// elección aún no ha iniciado ni fue cancelada.
+    pub(crate) fn set_gracia_registro(&mut self, gracia_ms: u64, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.gracia_ms = gracia_ms;
+                Ok(())
+            }
+        }
+    }
+
+    /// Establece la cantidad de `escaños` a cubrir, solo si la elección aún no ha iniciado
+    /// ni fue cancelada.
+    ///
+    /// Retorna `Error::EscañosInvalidos` si `escaños` es cero.
+    pub(crate) fn set_escaños(&mut self, escaños: u32, tiempo: u64) -> Result<(), Error> {
+        if escaños == 0 {
+            return Err(Error::EscañosInvalidos);
+        }
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.escaños = escaños;
+                Ok(())
+            }
+        }
+    }
+
+    /// Establece la `descripcion` de la elección, solo si aún no ha iniciado ni fue cancelada.
+    ///
+    /// Retorna `Error::DescripcionDemasiadoLarga` si supera `LARGO_MAXIMO_DESCRIPCION` bytes.
+    pub(crate) fn set_descripcion(&mut self, descripcion: String, tiempo: u64) -> Result<(), Error> {
+        if descripcion.len() > LARGO_MAXIMO_DESCRIPCION {
+            return Err(Error::DescripcionDemasiadoLarga);
+        }
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                self.descripcion = descripcion;
+                Ok(())
+            }
+        }
+    }
+
+    /// Permite que el votante `id_votante` vote al usuario `id_usuario` aunque no sea un
+    /// candidato formal. Si `id_usuario` es un votante aprobado que aún no es candidato, se
+    /// lo incorpora a los candidatos aprobados con el voto recibido, a continuación de los
+    /// existentes en la boleta. Sigue siendo votante, por lo que conserva su propio voto aun
+    /// si la elección no permite votar a los candidatos.
+    ///
+    /// Retorna `Error::WriteInNoPermitido` si la elección no permite votos write-in.
+    /// Retorna `Error::CandidatoNoExistente` si `id_usuario` no es votante ni candidato aprobado.
+    pub fn votar_write_in(
+        &mut self,
+        id_votante: AccountId,
+        id_usuario: AccountId,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if !self.permite_write_in {
+            return Err(Error::WriteInNoPermitido);
+        }
+        let es_candidato = self
+            .candidatos_aprobados
+            .iter()
+            .any(|c| c.get_account_id() == id_usuario);
+        if !es_candidato {
+            if !self.votantes_aprobados.iter().any(|v| v.id == id_usuario) {
+                return Err(Error::CandidatoNoExistente);
+            }
+            let mut candidato = Candidato::new(id_usuario);
+            candidato.orden = self
+                .candidatos_aprobados
+                .iter()
+                .map(|c| c.orden.saturating_add(1))
+                .max()
+                .unwrap_or(0);
+            candidato.write_in = true;
+            self.candidatos_aprobados.push(candidato);
+        }
+
+        let resultado = self.votar(id_votante, id_usuario, tiempo);
+        // Si el voto no fue válido el usuario no se incorpora a los candidatos
+        if resultado.is_err() && !es_candidato {
+            self.candidatos_aprobados.pop();
+        }
+        resultado
+    }
+
+    /// Establece el `peso` del voto del votante `id_votante`, esté aprobado o pendiente,
+    /// solo si la elección aún no ha iniciado ni fue cancelada.
+    pub(crate) fn set_peso_votante(
+        &mut self,
+        id_votante: &AccountId,
+        peso: u32,
+        tiempo: u64,
+    ) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::Pendiente => {
+                match self
+                    .votantes_pendientes
+                    .iter_mut()
+                    .chain(self.votantes_aprobados.iter_mut())
+                    .find(|v| v.id == *id_votante)
+                {
+                    Some(v) => {
+                        v.peso = peso;
+                        Ok(())
+                    }
+                    None => Err(Error::VotanteNoExistente),
+                }
+            }
+        }
+    }
+
+    /// Permite que el votante `id_votante` emita un voto en blanco.
+    /// No suma votos a ningún candidato, pero el votante no puede volver a votar.
+    /// Se incrementa la cantidad de votos en blanco de la elección.
+    /// Retorna `Error::CandidatoNoPuedeVotar` si el votante es también un candidato aprobado
+    /// y la elección no permite que los candidatos voten.
+    pub fn votar_en_blanco(&mut self, id_votante: AccountId, tiempo: u64) -> Result<(), Error> {
+        if self.cancelada {
+            return Err(Error::VotacionCancelada);
+        }
+        match self.consultar_estado(tiempo) {
+            EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+            EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+            EstadoDeEleccion::EnCurso => {
+                self.verificar_candidato_puede_votar(&id_votante)?;
+                match self.buscar_miembro_aprobado(&id_votante, &Rol::Votante) {
+                    Some(votante) => votante.votar()?,
+                    None => return Err(Error::VotanteNoExistente),
+                }
+                self.votos_en_blanco += 1;
+                Ok(())
+            }
+        }
+    }
+}
+
+mod tests {
+    #![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+    use crate::{
+        candidato::Candidato,
+        eleccion::{Eleccion, Miembro, Rol},
+        enums::{Error, EstadoDeEleccion},
+        fecha::Fecha,
+    };
+    use ink::primitives::AccountId;
+
+    #[test]
+    fn test_display_rol() {
+        assert_eq!(Rol::Candidato.to_string(), "Candidato");
+        assert_eq!(Rol::Votante.to_string(), "Votante");
+    }
+
+    #[test]
+    fn test_estado_eleccion() {
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+
+        let eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
+
+        assert_eq!(
+            eleccion.consultar_estado(1716138000000), // 19/5/2024 17:00:00
+            EstadoDeEleccion::Pendiente
+        );
+        assert_eq!(
+            eleccion.consultar_estado(1716163199000), // 19/5/2024 23:59:59
+            EstadoDeEleccion::Pendiente
+        );
+        assert_eq!(
+            eleccion.consultar_estado(1716163200000), // 20/5/2024 00:00:00
+            EstadoDeEleccion::EnCurso
+        );
+        assert_eq!(
+            eleccion.consultar_estado(1716224400000), // 20/5/2024 17:00:00
+            EstadoDeEleccion::EnCurso
         );
         assert_eq!(
             eleccion.consultar_estado(1716249599000), // 20/5/2024 23:59:59
@@ -329,19 +1124,30 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         eleccion
             .añadir_miembro(AccountId::from(miembro_id), Rol::Candidato, 0)
             .unwrap();
-        assert!(eleccion.existe_usuario(&AccountId::from(miembro_id)));
+        assert_eq!(
+            eleccion.existe_usuario(&AccountId::from(miembro_id)),
+            Some(Rol::Candidato)
+        );
 
         let miembro_id: [u8; 32] = [255; 32];
         eleccion
             .añadir_miembro(AccountId::from(miembro_id), Rol::Votante, 0)
             .unwrap();
-        assert!(eleccion.existe_usuario(&AccountId::from(miembro_id)));
+        assert_eq!(
+            eleccion.existe_usuario(&AccountId::from(miembro_id)),
+            Some(Rol::Votante)
+        );
+
+        let miembro_id: [u8; 32] = [127; 32];
+        assert!(eleccion
+            .existe_usuario(&AccountId::from(miembro_id))
+            .is_none());
     }
 
     #[test]
@@ -351,7 +1157,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let result =
@@ -380,7 +1186,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -407,7 +1213,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -433,7 +1239,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -443,7 +1249,7 @@ mod tests {
             .is_none());
 
         eleccion.aprobar_miembro(&m_id, &Rol::Candidato).unwrap();
-        assert!(eleccion.existe_usuario(&m_id));
+        assert_eq!(eleccion.existe_usuario(&m_id), Some(Rol::Candidato));
 
         let miembro_id: [u8; 32] = [255; 32];
         let m_id = AccountId::from(miembro_id);
@@ -460,7 +1266,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -471,6 +1277,39 @@ mod tests {
         assert!(eleccion.aprobar_miembro(&m_id, &Rol::Votante).is_err());
     }
 
+    #[test]
+    fn test_aprobacion_de_miembros_4() {
+        // Creacion
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
+        // Testeo
+        let m_id = AccountId::from([0; 32]);
+        eleccion.añadir_miembro(m_id, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&m_id, &Rol::Candidato).unwrap();
+        assert_eq!(
+            eleccion.aprobar_miembro(&m_id, &Rol::Candidato),
+            Err(Error::MiembroYaAprobado)
+        );
+
+        let m_id = AccountId::from([255; 32]);
+        eleccion.añadir_miembro(m_id, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&m_id, &Rol::Votante).unwrap();
+        assert_eq!(
+            eleccion.aprobar_miembro(&m_id, &Rol::Votante),
+            Err(Error::MiembroYaAprobado)
+        );
+
+        // Con otro rol el miembro no existe
+        assert_eq!(
+            eleccion.aprobar_miembro(&m_id, &Rol::Candidato),
+            Err(Error::CandidatoNoExistente)
+        );
+        assert_eq!(eleccion.votantes_aprobados.len(), 1);
+    }
+
     #[test]
     fn test_rechazar_miembros() {
         // Creacion
@@ -478,7 +1317,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -498,7 +1337,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -516,7 +1355,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -538,7 +1377,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -560,7 +1399,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
 
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
@@ -593,7 +1432,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -617,6 +1456,35 @@ mod tests {
         assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 2);
     }
 
+    #[test]
+    fn test_votar_candidato_rechazado() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([255; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.rechazar_miembro(&candidato, &Rol::Candidato).unwrap();
+        eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+
+        assert_eq!(
+            eleccion.votar(votante, candidato, 1716163200000),
+            Err(Error::CandidatoNoExistente)
+        );
+
+        // Un candidato que figura aprobado pero a la vez pendiente tampoco recibe votos
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.candidatos_aprobados.push(Candidato::new(candidato));
+        assert_eq!(
+            eleccion.votar(votante, candidato, 1716163200000),
+            Err(Error::CandidatoNoExistente)
+        );
+        assert!(!eleccion.votantes_aprobados[0].ha_votado);
+    }
+
     #[test]
     fn test_votar_2() {
         // Creacion
@@ -624,7 +1492,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -646,7 +1514,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -668,7 +1536,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -689,7 +1557,7 @@ mod tests {
         let puesto = "Presidente".to_string();
         let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
         let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
-        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin);
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
         // Testeo
         let miembro_id: [u8; 32] = [0; 32];
         let m_id = AccountId::from(miembro_id);
@@ -702,6 +1570,784 @@ mod tests {
 
         assert!(eleccion.votar(m_id2, m_id, 1716163200000).is_err());
     }
+
+    #[test]
+    fn test_votar_candidatos_pueden_votar() {
+        let candidato = AccountId::from([0; 32]);
+        let otro_candidato = AccountId::from([1; 32]);
+        let votante = AccountId::from([255; 32]);
+
+        for candidatos_pueden_votar in [true, false] {
+            let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+            let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+            let mut eleccion = Eleccion::new(
+                1,
+                "Presidente".to_string(),
+                fecha_inicio,
+                fecha_fin,
+                0,
+                0,
+                candidatos_pueden_votar,
+            );
+            // El candidato aprobado también es un votante aprobado
+            for id in [candidato, otro_candidato] {
+                eleccion.añadir_miembro(id, Rol::Candidato, 0).unwrap();
+                eleccion.aprobar_miembro(&id, &Rol::Candidato).unwrap();
+            }
+            for id in [candidato, votante] {
+                eleccion.añadir_miembro(id, Rol::Votante, 0).unwrap();
+                eleccion.aprobar_miembro(&id, &Rol::Votante).unwrap();
+            }
+
+            // Un votante que no es candidato siempre puede votar
+            assert_eq!(eleccion.votar(votante, candidato, 1716163200000), Ok(()));
+
+            if candidatos_pueden_votar {
+                assert_eq!(eleccion.votar(candidato, otro_candidato, 1716163200000), Ok(()));
+                assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+            } else {
+                assert_eq!(
+                    eleccion.votar(candidato, otro_candidato, 1716163200000),
+                    Err(Error::CandidatoNoPuedeVotar)
+                );
+                assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 0);
+
+                // Tampoco puede votar en blanco ni, por lo tanto, cambiar su voto
+                assert_eq!(
+                    eleccion.votar_en_blanco(candidato, 1716163200000),
+                    Err(Error::CandidatoNoPuedeVotar)
+                );
+                assert_eq!(
+                    eleccion.cambiar_voto(candidato, otro_candidato, 1716163200000),
+                    Err(Error::CandidatoNoPuedeVotar)
+                );
+                assert_eq!(eleccion.votos_en_blanco, 0);
+                assert!(!eleccion.votantes_aprobados[0].ha_votado);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verificar_registro() {
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([255; 32]);
+
+        for candidatos_pueden_votar in [true, false] {
+            let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+            let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+            let mut eleccion = Eleccion::new(
+                1,
+                "Presidente".to_string(),
+                fecha_inicio,
+                fecha_fin,
+                0,
+                0,
+                candidatos_pueden_votar,
+            );
+            eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+            eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+            eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+
+            // Nadie puede registrarse dos veces con el mismo rol
+            assert_eq!(
+                eleccion.verificar_registro(&candidato, &Rol::Candidato),
+                Err(Error::UsuarioYaEsCandidato)
+            );
+            assert_eq!(
+                eleccion.verificar_registro(&votante, &Rol::Votante),
+                Err(Error::UsuarioYaEsVotante)
+            );
+
+            // Solo si los candidatos pueden votar se admite el otro rol
+            if candidatos_pueden_votar {
+                assert_eq!(eleccion.verificar_registro(&candidato, &Rol::Votante), Ok(()));
+                assert_eq!(eleccion.verificar_registro(&votante, &Rol::Candidato), Ok(()));
+            } else {
+                assert_eq!(
+                    eleccion.verificar_registro(&candidato, &Rol::Votante),
+                    Err(Error::UsuarioYaEsCandidato)
+                );
+                assert_eq!(
+                    eleccion.verificar_registro(&votante, &Rol::Candidato),
+                    Err(Error::UsuarioYaEsVotante)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_finalistas() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let finalizada = 1716336000000; // 22/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let a = AccountId::from([1; 32]);
+        let b = AccountId::from([2; 32]);
+        let c = AccountId::from([3; 32]);
+        let d = AccountId::from([4; 32]);
+
+        // Sin finalizar no hay finalistas
+        assert_eq!(eleccion.get_finalistas(0), Err(Error::VotacionNoIniciada));
+        assert_eq!(
+            eleccion.get_finalistas(1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+
+        // Un único candidato no alcanza para una segunda vuelta
+        eleccion.candidatos_aprobados.push(Candidato::con_votos(a, 5));
+        assert_eq!(
+            eleccion.get_finalistas(finalizada),
+            Err(Error::CandidatosInsuficientes)
+        );
+
+        // B y C empatan en el segundo lugar, se prioriza el menor AccountId
+        eleccion.candidatos_aprobados.push(Candidato::con_votos(c, 3));
+        eleccion.candidatos_aprobados.push(Candidato::con_votos(d, 1));
+        eleccion.candidatos_aprobados.push(Candidato::con_votos(b, 3));
+        assert_eq!(eleccion.get_finalistas(finalizada), Ok(vec![a, b]));
+    }
+
+    #[test]
+    fn test_añadir_miembro_fin_registro() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        // Por defecto el registro cierra al iniciar la elección
+        assert_eq!(
+            eleccion.fin_registro.get_tiempo_unix(),
+            eleccion.inicio.get_tiempo_unix()
+        );
+
+        // El registro cierra el 19/05/2024 00:00:00
+        eleccion.fin_registro = Fecha::new(0, 0, 0, 19, 5, 2024);
+        let cierre = eleccion.fin_registro.get_tiempo_unix();
+
+        let m_id = AccountId::from([0; 32]);
+        let m_id2 = AccountId::from([1; 32]);
+
+        // Justo antes del cierre es posible registrarse
+        assert_eq!(eleccion.añadir_miembro(m_id, Rol::Votante, cierre - 1), Ok(()));
+
+        // Al cierre la elección sigue pendiente, pero el registro está cerrado
+        assert_eq!(eleccion.consultar_estado(cierre), EstadoDeEleccion::Pendiente);
+        assert_eq!(
+            eleccion.añadir_miembro(m_id2, Rol::Candidato, cierre),
+            Err(Error::RegistroCerrado)
+        );
+        assert!(eleccion.candidatos_pendientes.is_empty());
+    }
+
+    #[test]
+    fn test_añadir_miembro_gracia_registro() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+        let inicio = eleccion.inicio.get_tiempo_unix();
+
+        let m_id = AccountId::from([0; 32]);
+        let m_id2 = AccountId::from([1; 32]);
+        let m_id3 = AccountId::from([2; 32]);
+
+        // Sin margen de gracia no es posible registrarse al inicio
+        assert_eq!(eleccion.gracia_ms, 0);
+        assert_eq!(
+            eleccion.añadir_miembro(m_id, Rol::Votante, inicio),
+            Err(Error::VotacionEnCurso)
+        );
+
+        // Margen de gracia de un minuto, establecido antes del inicio
+        assert_eq!(
+            eleccion.set_gracia_registro(60_000, inicio),
+            Err(Error::VotacionEnCurso)
+        );
+        eleccion.set_gracia_registro(60_000, 0).unwrap();
+
+        // Dentro del margen es posible registrarse
+        assert_eq!(eleccion.añadir_miembro(m_id, Rol::Votante, inicio), Ok(()));
+        assert_eq!(
+            eleccion.añadir_miembro(m_id2, Rol::Candidato, inicio + 59_999),
+            Ok(())
+        );
+
+        // Fuera del margen el registro es rechazado
+        assert_eq!(
+            eleccion.añadir_miembro(m_id3, Rol::Votante, inicio + 60_000),
+            Err(Error::VotacionEnCurso)
+        );
+        assert_eq!(eleccion.get_conteos(), (1, 0, 1, 0));
+
+        // Si el registro cierra antes del inicio, el margen no aplica
+        eleccion.fin_registro = Fecha::new(0, 0, 0, 19, 5, 2024);
+        assert_eq!(
+            eleccion.añadir_miembro(m_id3, Rol::Votante, inicio),
+            Err(Error::VotacionEnCurso)
+        );
+    }
+
+    #[test]
+    fn test_finalizar_anticipadamente() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        // Aún no iniciada
+        assert_eq!(
+            eleccion.finalizar_anticipadamente(0),
+            Err(Error::VotacionNoIniciada)
+        );
+
+        // Se finaliza el 20/05/2024 12:00:00
+        eleccion.finalizar_anticipadamente(1716206400000).unwrap();
+        assert_eq!(eleccion.fin.get_tiempo_unix(), 1716206400000);
+        assert_eq!(eleccion.consultar_estado(1716206400000), EstadoDeEleccion::Finalizada);
+
+        // Ya finalizada
+        assert_eq!(
+            eleccion.finalizar_anticipadamente(1716206400000),
+            Err(Error::VotacionFinalizada)
+        );
+    }
+
+    #[test]
+    fn test_get_resultado_final() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([255; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato, &Rol::Candidato).unwrap();
+        eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+        eleccion.votar(votante, candidato, 1716163200000).unwrap();
+
+        // Aún en curso
+        assert_eq!(
+            eleccion.get_resultado_final(1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+        assert!(eleccion.resultado_final.is_none());
+
+        // Se calcula en la primera consulta una vez finalizada
+        assert_eq!(
+            eleccion.get_resultado_final(1716249600000),
+            Ok(vec![(candidato, 1)])
+        );
+        assert_eq!(eleccion.resultado_final, Some(vec![(candidato, 1)]));
+
+        // Las siguientes consultas retornan el mismo resultado, aunque cambien los candidatos
+        eleccion.candidatos_aprobados[0].votar().unwrap();
+        assert_eq!(
+            eleccion.get_resultado_final(1716249600000),
+            Ok(vec![(candidato, 1)])
+        );
+    }
+
+    #[test]
+    fn test_candidatos_ordenados() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let a = AccountId::from([0; 32]);
+        let b = AccountId::from([1; 32]);
+        let c = AccountId::from([2; 32]);
+        for id in [a, b, c] {
+            eleccion.añadir_miembro(id, Rol::Candidato, 0).unwrap();
+            eleccion.aprobar_miembro(&id, &Rol::Candidato).unwrap();
+        }
+
+        // Sin orden configurado se respeta el orden de aprobación
+        assert_eq!(eleccion.get_candidatos_ordenados(), vec![(a, 0), (b, 0), (c, 0)]);
+
+        // C primero, A y B empatan y conservan su orden de aprobación
+        eleccion.set_orden_candidato(&c, 1, 0).unwrap();
+        eleccion.set_orden_candidato(&a, 2, 0).unwrap();
+        eleccion.set_orden_candidato(&b, 2, 0).unwrap();
+        assert_eq!(eleccion.get_candidatos_ordenados(), vec![(c, 1), (a, 2), (b, 2)]);
+
+        assert_eq!(
+            eleccion.set_orden_candidato(&AccountId::from([3; 32]), 0, 0),
+            Err(Error::CandidatoNoExistente)
+        );
+
+        // Una vez iniciada la elección el orden no puede modificarse
+        assert_eq!(
+            eleccion.set_orden_candidato(&a, 0, 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+    }
+
+    #[test]
+    fn test_set_perfil_candidato() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let pendiente = AccountId::from([0; 32]);
+        let aprobado = AccountId::from([1; 32]);
+        let votante = AccountId::from([2; 32]);
+        eleccion.añadir_miembro(pendiente, Rol::Candidato, 0).unwrap();
+        eleccion.añadir_miembro(aprobado, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&aprobado, &Rol::Candidato).unwrap();
+        eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+
+        // Tanto un candidato pendiente como uno aprobado pueden establecer su perfil
+        eleccion
+            .set_perfil_candidato(&pendiente, Some("Partido A".to_string()), None, 0)
+            .unwrap();
+        eleccion
+            .set_perfil_candidato(
+                &aprobado,
+                Some("Partido B".to_string()),
+                Some("Propuesta B".to_string()),
+                0,
+            )
+            .unwrap();
+        assert_eq!(eleccion.candidatos_pendientes[0].partido, Some("Partido A".to_string()));
+
+        // Solo se informan los perfiles de los candidatos aprobados
+        assert_eq!(
+            eleccion.get_perfiles_candidatos(),
+            vec![(
+                aprobado,
+                Some("Partido B".to_string()),
+                Some("Propuesta B".to_string())
+            )]
+        );
+
+        // Un votante no es candidato
+        assert_eq!(
+            eleccion.set_perfil_candidato(&votante, None, None, 0),
+            Err(Error::CandidatoNoExistente)
+        );
+
+        // Una vez iniciada la elección el perfil no puede modificarse
+        assert_eq!(
+            eleccion.set_perfil_candidato(&aprobado, None, None, 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+        assert_eq!(eleccion.candidatos_aprobados[0].partido, Some("Partido B".to_string()));
+    }
+
+    #[test]
+    fn test_set_escaños() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Concejales".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        // Por defecto se cubre un único escaño
+        assert_eq!(eleccion.escaños, 1);
+
+        assert_eq!(eleccion.set_escaños(0, 0), Err(Error::EscañosInvalidos));
+        assert_eq!(eleccion.set_escaños(3, 0), Ok(()));
+        assert_eq!(eleccion.escaños, 3);
+
+        // Una vez iniciada la elección no es posible modificarlos
+        assert_eq!(
+            eleccion.set_escaños(5, 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+        assert_eq!(eleccion.escaños, 3);
+    }
+
+    #[test]
+    fn test_set_descripcion() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        // Por defecto no tiene descripción
+        assert!(eleccion.descripcion.is_empty());
+
+        eleccion
+            .set_descripcion("Elección del presidente del club".to_string(), 0)
+            .unwrap();
+        assert_eq!(eleccion.descripcion, "Elección del presidente del club");
+
+        // Se admiten hasta 512 bytes
+        assert_eq!(eleccion.set_descripcion("a".repeat(512), 0), Ok(()));
+        assert_eq!(
+            eleccion.set_descripcion("a".repeat(513), 0),
+            Err(Error::DescripcionDemasiadoLarga)
+        );
+        assert_eq!(eleccion.descripcion.len(), 512);
+
+        // Una vez iniciada la elección no es posible modificarla
+        assert_eq!(
+            eleccion.set_descripcion(String::new(), 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+    }
+
+    #[test]
+    fn test_modo_invitacion() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let invitado = AccountId::from([0; 32]);
+        let no_invitado = AccountId::from([1; 32]);
+
+        // Por defecto cualquiera puede registrarse
+        assert!(!eleccion.modo_invitacion);
+        assert!(eleccion.esta_invitado(&invitado));
+        assert!(eleccion.esta_invitado(&no_invitado));
+
+        // Invitar dos veces a la misma cuenta no la duplica
+        eleccion.invitar(vec![invitado, invitado], 0).unwrap();
+        assert_eq!(eleccion.invitados, vec![invitado]);
+
+        eleccion.set_modo_invitacion(true, 0).unwrap();
+        assert!(eleccion.esta_invitado(&invitado));
+        assert!(!eleccion.esta_invitado(&no_invitado));
+
+        // Una vez iniciada la elección no es posible modificar las invitaciones
+        assert_eq!(
+            eleccion.invitar(vec![no_invitado], 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+        assert_eq!(
+            eleccion.set_modo_invitacion(false, 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+    }
+
+    #[test]
+    fn test_votar_con_nonce() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([1; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato, &Rol::Candidato).unwrap();
+        eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+
+        // Un voto fallido no registra el nonce
+        assert_eq!(
+            eleccion.votar_con_nonce(votante, candidato, 7, 0),
+            Err(Error::VotacionNoIniciada)
+        );
+        assert_eq!(eleccion.votantes_aprobados[0].nonce, None);
+
+        assert_eq!(eleccion.votar_con_nonce(votante, candidato, 7, 1716163200000), Ok(true));
+        assert_eq!(eleccion.votantes_aprobados[0].nonce, Some(7));
+
+        // El reenvío con el mismo nonce no suma un nuevo voto
+        assert_eq!(eleccion.votar_con_nonce(votante, candidato, 7, 1716163200000), Ok(false));
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
+
+        // Con otro nonce se trata de un segundo voto
+        assert_eq!(
+            eleccion.votar_con_nonce(votante, candidato, 8, 1716163200000),
+            Err(Error::VotanteYaVoto)
+        );
+        assert_eq!(eleccion.votantes_aprobados[0].nonce, Some(7));
+    }
+
+    #[test]
+    fn test_votar_write_in() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([1; 32]);
+        let votante2 = AccountId::from([2; 32]);
+        let ajeno = AccountId::from([3; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato, &Rol::Candidato).unwrap();
+        for id in [votante, votante2] {
+            eleccion.añadir_miembro(id, Rol::Votante, 0).unwrap();
+            eleccion.aprobar_miembro(&id, &Rol::Votante).unwrap();
+        }
+
+        // Por defecto no se permiten votos write-in
+        assert_eq!(
+            eleccion.votar_write_in(votante, votante2, 1716163200000),
+            Err(Error::WriteInNoPermitido)
+        );
+
+        // Solo es posible habilitarlos antes del inicio
+        assert_eq!(
+            eleccion.set_permite_write_in(true, 1716163200000),
+            Err(Error::VotacionEnCurso)
+        );
+        eleccion.set_permite_write_in(true, 0).unwrap();
+
+        // Un usuario ajeno a la elección no puede ser votado
+        assert_eq!(
+            eleccion.votar_write_in(votante, ajeno, 1716163200000),
+            Err(Error::CandidatoNoExistente)
+        );
+
+        // Antes del inicio el voto falla y el votante no se incorpora como candidato
+        assert_eq!(
+            eleccion.votar_write_in(votante, votante2, 0),
+            Err(Error::VotacionNoIniciada)
+        );
+        assert_eq!(eleccion.candidatos_aprobados.len(), 1);
+
+        // Votante vota a Votante2, que se incorpora con un voto
+        eleccion.votar_write_in(votante, votante2, 1716163200000).unwrap();
+        assert_eq!(eleccion.get_candidatos_verificados(), vec![candidato, votante2]);
+        assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+
+        // Votante2 queda a continuación del candidato formal en la boleta
+        assert_eq!(
+            eleccion.get_candidatos_ordenados(),
+            vec![(candidato, 0), (votante2, 1)]
+        );
+
+        // Votante2 se vota a sí mismo, ya como candidato
+        eleccion.votar_write_in(votante2, votante2, 1716163200000).unwrap();
+        assert_eq!(eleccion.candidatos_aprobados.len(), 2);
+        assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 2);
+    }
+
+    #[test]
+    fn test_votar_write_in_candidatos_no_votan() {
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion =
+            Eleccion::new(1, "Presidente".to_string(), fecha_inicio, fecha_fin, 0, 0, false);
+        eleccion.set_permite_write_in(true, 0).unwrap();
+
+        // El candidato formal también es votante aprobado, con orden 3 en la boleta
+        let candidato = AccountId::from([0; 32]);
+        let votante = AccountId::from([1; 32]);
+        let votante2 = AccountId::from([2; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato, &Rol::Candidato).unwrap();
+        eleccion.set_orden_candidato(&candidato, 3, 0).unwrap();
+        for id in [candidato, votante, votante2] {
+            eleccion.añadir_miembro(id, Rol::Votante, 0).unwrap();
+            eleccion.aprobar_miembro(&id, &Rol::Votante).unwrap();
+        }
+
+        // Votante vota a Votante2, que se incorpora luego del candidato formal
+        eleccion.votar_write_in(votante, votante2, 1716163200000).unwrap();
+        assert_eq!(
+            eleccion.get_candidatos_ordenados(),
+            vec![(candidato, 3), (votante2, 4)]
+        );
+
+        // El candidato formal no puede votar, pero Votante2 conserva su voto
+        assert_eq!(
+            eleccion.votar(candidato, votante2, 1716163200000),
+            Err(Error::CandidatoNoPuedeVotar)
+        );
+        eleccion.votar(votante2, candidato, 1716163200000).unwrap();
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
+        assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+    }
+
+    #[test]
+    fn test_votar_en_blanco() {
+        // Creacion
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
+        // Testeo
+        let miembro_id: [u8; 32] = [0; 32];
+        let m_id = AccountId::from(miembro_id);
+        eleccion.añadir_miembro(m_id, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&m_id, &Rol::Candidato).unwrap();
+
+        let miembro_id2: [u8; 32] = [255; 32];
+        let m_id2 = AccountId::from(miembro_id2);
+        eleccion.añadir_miembro(m_id2, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&m_id2, &Rol::Votante).unwrap();
+
+        // Antes de iniciar la elección no es posible votar en blanco
+        assert_eq!(
+            eleccion.votar_en_blanco(m_id2, 0),
+            Err(Error::VotacionNoIniciada)
+        );
+
+        // El candidato no puede votar en blanco, no es votante
+        assert_eq!(
+            eleccion.votar_en_blanco(m_id, 1716163200000),
+            Err(Error::VotanteNoExistente)
+        );
+
+        assert_eq!(eleccion.votos_en_blanco, 0);
+        assert!(eleccion.votar_en_blanco(m_id2, 1716163200000).is_ok());
+        assert_eq!(eleccion.votantes_aprobados[0].get_votos(), 1);
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 0);
+        assert_eq!(eleccion.votos_en_blanco, 1);
+
+        // No puede volver a votar, ni en blanco ni a un candidato
+        assert_eq!(
+            eleccion.votar_en_blanco(m_id2, 1716163200000),
+            Err(Error::VotanteYaVoto)
+        );
+        assert_eq!(
+            eleccion.votar(m_id2, m_id, 1716163200000),
+            Err(Error::VotanteYaVoto)
+        );
+
+        assert_eq!(eleccion.votos_en_blanco, 1);
+
+        // Si cambia su voto a un candidato deja de contar como voto en blanco
+        eleccion.cambiar_voto(m_id2, m_id, 1716163200000).unwrap();
+        assert_eq!(eleccion.votos_en_blanco, 0);
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
+
+        // Tras finalizar no es posible votar en blanco
+        assert_eq!(
+            eleccion.votar_en_blanco(m_id2, 1716249600000),
+            Err(Error::VotacionFinalizada)
+        );
+    }
+
+    #[test]
+    fn test_cancelar() {
+        // Creacion
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
+        // Testeo
+        let miembro_id: [u8; 32] = [0; 32];
+        let m_id = AccountId::from(miembro_id);
+        eleccion.añadir_miembro(m_id, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&m_id, &Rol::Votante).unwrap();
+
+        // No es posible cancelar una elección en curso o finalizada
+        assert_eq!(eleccion.cancelar(1716163200000), Err(Error::VotacionEnCurso));
+        assert_eq!(eleccion.cancelar(1716249600000), Err(Error::VotacionFinalizada));
+
+        assert!(eleccion.cancelar(0).is_ok());
+        assert_eq!(eleccion.cancelar(0), Err(Error::VotacionCancelada));
+
+        // Una elección cancelada no admite nuevos miembros ni votos
+        let miembro_id2: [u8; 32] = [255; 32];
+        let m_id2 = AccountId::from(miembro_id2);
+        assert_eq!(
+            eleccion.añadir_miembro(m_id2, Rol::Candidato, 0),
+            Err(Error::VotacionCancelada)
+        );
+        assert_eq!(
+            eleccion.votar_en_blanco(m_id, 1716163200000),
+            Err(Error::VotacionCancelada)
+        );
+    }
+
+    #[test]
+    fn test_cambiar_voto() {
+        // Creacion
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 0, 0, true);
+        // Testeo
+        let candidato1 = AccountId::from([0; 32]);
+        eleccion.añadir_miembro(candidato1, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato1, &Rol::Candidato).unwrap();
+
+        let candidato2 = AccountId::from([1; 32]);
+        eleccion.añadir_miembro(candidato2, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato2, &Rol::Candidato).unwrap();
+
+        let votante = AccountId::from([255; 32]);
+        eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+        eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+
+        // No puede cambiar su voto si aún no votó
+        assert_eq!(
+            eleccion.cambiar_voto(votante, candidato2, 1716163200000),
+            Err(Error::VotanteNoHaVotado)
+        );
+
+        eleccion.votar(votante, candidato1, 1716163200000).unwrap();
+        assert_eq!(eleccion.votantes_aprobados[0].voto_a, Some(candidato1));
+
+        // Cambia su voto al segundo candidato
+        assert!(eleccion.cambiar_voto(votante, candidato2, 1716163200000).is_ok());
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 0);
+        assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+        assert_eq!(eleccion.votantes_aprobados[0].voto_a, Some(candidato2));
+
+        // Vuelve a cambiar su voto al primer candidato
+        assert!(eleccion.cambiar_voto(votante, candidato1, 1716163200000).is_ok());
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
+        assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 0);
+
+        // Un candidato inexistente no altera el recuento
+        assert_eq!(
+            eleccion.cambiar_voto(votante, AccountId::from([7; 32]), 1716163200000),
+            Err(Error::CandidatoNoExistente)
+        );
+        assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
+
+        // Tras finalizar no es posible cambiar el voto
+        assert_eq!(
+            eleccion.cambiar_voto(votante, candidato2, 1716249600000),
+            Err(Error::VotacionFinalizada)
+        );
+    }
+
+    #[test]
+    fn test_quorum_alcanzado() {
+        // Creacion
+        let id = 1;
+        let puesto = "Presidente".to_string();
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let mut eleccion = Eleccion::new(id, puesto, fecha_inicio, fecha_fin, 2, 0, true);
+        // Testeo
+        let candidato = AccountId::from([0; 32]);
+        eleccion.añadir_miembro(candidato, Rol::Candidato, 0).unwrap();
+        eleccion.aprobar_miembro(&candidato, &Rol::Candidato).unwrap();
+
+        for i in 1..=3 {
+            let votante = AccountId::from([i; 32]);
+            eleccion.añadir_miembro(votante, Rol::Votante, 0).unwrap();
+            eleccion.aprobar_miembro(&votante, &Rol::Votante).unwrap();
+        }
+
+        // Sin votos no se alcanza el quorum
+        assert_eq!(eleccion.get_cantidad_votos(), 0);
+        assert!(!eleccion.quorum_alcanzado());
+
+        eleccion.votar(AccountId::from([1; 32]), candidato, 1716163200000).unwrap();
+        assert!(!eleccion.quorum_alcanzado());
+
+        // Los votos en blanco también cuentan para el quorum
+        eleccion.votar_en_blanco(AccountId::from([2; 32]), 1716163200000).unwrap();
+        assert_eq!(eleccion.get_cantidad_votos(), 2);
+        assert!(eleccion.quorum_alcanzado());
+
+        // Una elección sin quorum siempre lo alcanza
+        let fecha_inicio = Fecha::new(0, 0, 0, 20, 5, 2024); // 20/05/2024 00:00:00
+        let fecha_fin = Fecha::new(0, 0, 0, 21, 5, 2024); // 21/05/2024 00:00:00
+        let eleccion =
+            Eleccion::new(2, "Gobernador".to_string(), fecha_inicio, fecha_fin, 0, 0, true);
+        assert!(eleccion.quorum_alcanzado());
+    }
 }
 
 //cargo tarpaulin --target-dir src/coverage --skip-clean --exclude-files=target/debug/* --out html
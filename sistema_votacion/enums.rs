@@ -22,6 +22,42 @@ pub enum EstadoDeEleccion {
     Finalizada,
 }
 
+impl EstadoDeEleccion {
+    /// Verifica que la elección haya finalizado antes de exponer sus resultados.
+    ///
+    /// Retorna `Error::VotacionNoIniciada` si está pendiente y `Error::VotacionEnCurso`
+    /// si se encuentra abierta.
+    pub fn requiere_finalizada(&self) -> Result<(), Error> {
+        match self {
+            EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+            EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
+            EstadoDeEleccion::Finalizada => Ok(()),
+        }
+    }
+}
+
+impl Display for EstadoDeEleccion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EstadoDeEleccion::Pendiente => write!(f, "Pendiente"),
+            EstadoDeEleccion::EnCurso => write!(f, "En curso"),
+            EstadoDeEleccion::Finalizada => write!(f, "Finalizada"),
+        }
+    }
+}
+
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+#[derive(Debug, PartialEq)]
+/// Estados que puede tener un usuario dentro de una eleccion según su rol y aprobación
+pub enum EstadoMiembro {
+    NoRegistrado,
+    VotantePendiente,
+    VotanteAprobado,
+    CandidatoPendiente,
+    CandidatoAprobado,
+}
+
 /// Representa un error al llamar a un metodo del sistema.
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
@@ -30,10 +66,10 @@ pub enum Error {
     PermisosInsuficientes,      // Intentar acceder a un metodo que no le corresponde al usuario.
     UsuarioExistente,           // Intentar registrar un usuario que ya existe.
     UsuarioNoExistente,         // Intentar registrar como votante/candidato a un usuario que no existe.
-    UsuarioNoPermitido,         // Intentar registrar administrador como usuario del sistema (futuro candidato o miembro)
+    UsuarioNoPermitido,         // Intentar registrar administrador o contrato de reportes como usuario del sistema (futuro candidato o miembro)
     VotanteExistente,           // Intentar registrar un votante que ya existe.
     CandidatoExistente,         // Intentar registrar un candidato que ya existe.
-    MiembroExistente,           // Intentar registrar un miembro que ya existe.
+    MiembroExistente,           // Intentar registrar un miembro que ya existe (reemplazado por UsuarioYaEsCandidato/UsuarioYaEsVotante).
     VotanteNoExistente,         // Intentar aprobar un votante que no existe.
     CandidatoNoExistente,       // Intentar aprobar un candidato que no existe.
     VotacionNoExiste,           // Intentar registrar un votante en una eleccion que no existe.
@@ -41,8 +77,31 @@ pub enum Error {
     VotacionEnCurso,            // Intentar registrar a un miembro en una eleccion que ya inicio.
     VotacionFinalizada,         // La votación finalizó, no es posible operar
     VotanteYaVoto,              // El votante ya votó, no puede hacerlo dos veces
-    FechaFinalizacionInvalida,  // Se intenta crear una elección donde la fecha fin > inicio
-    FechaInvalida               // La fecha introducida no existe (no es valida)
+    FechaFinalizacionInvalida,  // Se intenta crear una elección donde la fecha inicio >= fin
+    FechaInvalida,              // La fecha introducida no existe (no es valida)
+    VotacionCancelada,          // La votación fue cancelada por el administrador, no es posible operar
+    VotanteNoHaVotado,          // El votante intenta cambiar su voto sin haber votado
+    DniInvalido,                // El DNI introducido no tiene un formato valido
+    CandidatosInsuficientes,    // Intentar validar una eleccion con menos de dos candidatos aprobados.
+    UsuarioYaEsCandidato,       // Intentar registrar en una eleccion a un usuario ya registrado como candidato.
+    UsuarioYaEsVotante,         // Intentar registrar en una eleccion a un usuario ya registrado como votante.
+    LimiteEleccionesAlcanzado,  // Intentar crear una eleccion cuando no quedan ids disponibles.
+    AdminExistente,             // Intentar agregar un administrador que ya lo es.
+    AdminNoExistente,           // Intentar remover un administrador que no lo es.
+    UltimoAdmin,                // Intentar remover al único administrador del sistema.
+    LimiteVotosAlcanzado,       // Un candidato no puede recibir más votos sin desbordar su contador.
+    PuestoInvalido,             // Intentar crear una eleccion con un puesto vacío.
+    ContratoReportesInvalido,   // Intentar que el contrato de reportes y un administrador coincidan, o usar al propio contrato.
+    CandidatoNoPuedeVotar,      // Un candidato aprobado intenta votar en una eleccion que no lo permite.
+    RegistroCerrado,            // Intentar registrarse en una eleccion cuyo registro ya cerró.
+    MiembroYaAprobado,          // Intentar aprobar a un miembro que ya fue aprobado.
+    WriteInNoPermitido,         // Intentar votar a un no candidato en una eleccion que no lo permite.
+    EscañosInvalidos,           // Intentar establecer cero escaños en una eleccion.
+    DescripcionDemasiadoLarga,  // Intentar establecer una descripcion de mas de 512 bytes.
+    UsuarioMiembroDeEleccion,   // Intentar eliminar a un miembro aprobado de una eleccion.
+    NoInvitado,                 // Intentar registrarse en una eleccion por invitación sin haber sido invitado.
+    OverflowAritmetico,         // Un cálculo de un reporte excede el rango de su resultado.
+    FechaFinRegistroInvalida,   // Se intenta crear una elección cuyo registro cierra después del inicio.
 }
 
 impl Display for Error {
@@ -60,7 +119,7 @@ impl Display for Error {
             }
             Error::UsuarioNoPermitido => write!(
                 f,
-                "El usuario que intenta registrar es el administrador del sistema"
+                "El usuario que intenta registrar es el administrador del sistema o el contrato de reportes"
             ),
             Error::VotanteExistente => write!(f, "El votante ya se encuentra registrado"),
             Error::CandidatoExistente => write!(f, "El candidato ya se encuentra registrado"),
@@ -81,6 +140,82 @@ impl Display for Error {
                 "La fecha de finalizacion ingresada no es consistente con la de inicio"
             ),
             Error::FechaInvalida => write!(f, "La fecha ingresada no es valida"),
+            Error::VotacionCancelada => write!(f, "La votación solicitada fue cancelada"),
+            Error::VotanteNoHaVotado => write!(f, "El votante solicitado aún no ha votado"),
+            Error::DniInvalido => write!(
+                f,
+                "El DNI ingresado debe contener entre 7 y 8 dígitos numéricos"
+            ),
+            Error::CandidatosInsuficientes => write!(
+                f,
+                "La elección debe contar con al menos dos candidatos aprobados"
+            ),
+            Error::UsuarioYaEsCandidato => {
+                write!(f, "El usuario ya se encuentra registrado como candidato")
+            }
+            Error::UsuarioYaEsVotante => {
+                write!(f, "El usuario ya se encuentra registrado como votante")
+            }
+            Error::LimiteEleccionesAlcanzado => {
+                write!(f, "Se alcanzó el límite de elecciones del sistema")
+            }
+            Error::AdminExistente => write!(f, "El usuario ya es administrador del sistema"),
+            Error::AdminNoExistente => write!(f, "El usuario no es administrador del sistema"),
+            Error::UltimoAdmin => write!(
+                f,
+                "No es posible remover al único administrador del sistema"
+            ),
+            Error::LimiteVotosAlcanzado => write!(
+                f,
+                "El candidato alcanzó la cantidad máxima de votos admitida"
+            ),
+            Error::PuestoInvalido => write!(f, "El puesto de la elección no puede estar vacío"),
+            Error::ContratoReportesInvalido => write!(
+                f,
+                "El contrato de reportes no puede ser un administrador ni el contrato de votación"
+            ),
+            Error::CandidatoNoPuedeVotar => write!(
+                f,
+                "Los candidatos no pueden votar en la elección solicitada"
+            ),
+            Error::RegistroCerrado => {
+                write!(f, "El registro de la elección solicitada ya se encuentra cerrado")
+            }
+            Error::MiembroYaAprobado => write!(f, "El miembro ya se encuentra aprobado"),
+            Error::WriteInNoPermitido => write!(
+                f,
+                "La elección solicitada no permite votar a usuarios que no son candidatos"
+            ),
+            Error::EscañosInvalidos => {
+                write!(f, "La elección debe contar con al menos un escaño")
+            }
+            Error::DescripcionDemasiadoLarga => write!(
+                f,
+                "La descripción de la elección no puede superar los 512 bytes"
+            ),
+            Error::UsuarioMiembroDeEleccion => {
+                write!(f, "El usuario es miembro aprobado de una elección")
+            }
+            Error::NoInvitado => write!(f, "El usuario no fue invitado a la elección"),
+            Error::OverflowAritmetico => {
+                write!(f, "El cálculo excede el rango de valores admitido")
+            }
+            Error::FechaFinRegistroInvalida => write!(
+                f,
+                "La fecha de cierre del registro no puede ser posterior a la de inicio"
+            ),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probar_display_estado_de_eleccion() {
+        assert_eq!(EstadoDeEleccion::Pendiente.to_string(), "Pendiente");
+        assert_eq!(EstadoDeEleccion::EnCurso.to_string(), "En curso");
+        assert_eq!(EstadoDeEleccion::Finalizada.to_string(), "Finalizada");
+    }
+}
@@ -1,10 +1,12 @@
+use core::cmp::Ordering;
+use core::fmt::Display;
 use core::panic;
 use crate::enums::Error;
 
 /// Representa una marca de tiempo y su tiempo unix correspondiente
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Fecha {
     segundo: u8,
     minuto: u8,
@@ -65,29 +67,83 @@ impl Fecha {
     }
 
     /// Crea una instancia de `Fecha`
-    /// 
-    /// # Panics
-    /// Produce panic si fecha 
-    /// no es válida.
-    pub fn new(segundo: u8, minuto: u8, hora: u8, dia: u8, mes: u8, año: u16) -> Fecha {
-        let dias = Fecha::dias_desde_epoch(año, mes, dia);
-        let segundos = (hora as u64 * 3600) + (minuto as u64 * 60) + segundo as u64;
-
-        let tiempo_unix: u64 = (dias * 86400 + segundos) * 1000;
-
-        let f = Fecha {
+    ///
+    /// Retorna `Error::FechaInvalida` si la fecha no es válida.
+    pub fn try_new(
+        segundo: u8,
+        minuto: u8,
+        hora: u8,
+        dia: u8,
+        mes: u8,
+        año: u16,
+    ) -> Result<Fecha, Error> {
+        let mut f = Fecha {
             segundo,
             minuto,
             hora,
             dia,
             mes,
             año,
-            tiempo_unix,
+            tiempo_unix: 0,
         };
 
-        match f.es_fecha_valida() {
-            true => f,
-            false => panic!("{}", Error::FechaInvalida),
+        // Se valida antes de calcular el tiempo unix, ya que este
+        // cálculo no es posible con un mes o día inválido
+        if !f.es_fecha_valida() {
+            return Err(Error::FechaInvalida);
+        }
+
+        let dias = Fecha::dias_desde_epoch(año, mes, dia);
+        let segundos = (hora as u64 * 3600) + (minuto as u64 * 60) + segundo as u64;
+        f.tiempo_unix = (dias * 86400 + segundos) * 1000;
+
+        Ok(f)
+    }
+
+    /// Crea la `Fecha` correspondiente al tiempo unix `tiempo_unix`, en milisegundos.
+    /// Se conserva el tiempo unix exacto, aunque la fecha solo represente segundos completos.
+    pub fn desde_tiempo_unix(tiempo_unix: u64) -> Fecha {
+        let segundos = tiempo_unix / 1000;
+        let mut dias = segundos / 86400;
+
+        let mut año: u16 = 1970;
+        loop {
+            let dias_año = if Fecha::es_bisiesto(año) { 366 } else { 365 };
+            if dias < dias_año {
+                break;
+            }
+            dias -= dias_año;
+            año += 1;
+        }
+
+        let mut mes: u8 = 1;
+        while dias >= Fecha::dias_en_mes(año, mes) as u64 {
+            dias -= Fecha::dias_en_mes(año, mes) as u64;
+            mes += 1;
+        }
+
+        let segundos_dia = segundos % 86400;
+        Fecha {
+            segundo: (segundos_dia % 60) as u8,
+            minuto: (segundos_dia / 60 % 60) as u8,
+            hora: (segundos_dia / 3600) as u8,
+            dia: dias as u8 + 1,
+            mes,
+            año,
+            tiempo_unix,
+        }
+    }
+
+    /// Crea una instancia de `Fecha`
+    ///
+    /// # Panics
+    /// Produce panic si fecha
+    /// no es válida.
+    #[cfg(test)]
+    pub fn new(segundo: u8, minuto: u8, hora: u8, dia: u8, mes: u8, año: u16) -> Fecha {
+        match Fecha::try_new(segundo, minuto, hora, dia, mes, año) {
+            Ok(f) => f,
+            Err(e) => panic!("{}", e),
         }
     }
 
@@ -95,6 +151,86 @@ impl Fecha {
     pub fn get_tiempo_unix(&self) -> u64 {
         self.tiempo_unix
     }
+
+    /// Devuelve el segundo de la fecha
+    pub fn get_segundo(&self) -> u8 {
+        self.segundo
+    }
+
+    /// Devuelve el minuto de la fecha
+    pub fn get_minuto(&self) -> u8 {
+        self.minuto
+    }
+
+    /// Devuelve la hora de la fecha
+    pub fn get_hora(&self) -> u8 {
+        self.hora
+    }
+
+    /// Devuelve el día de la fecha
+    pub fn get_dia(&self) -> u8 {
+        self.dia
+    }
+
+    /// Devuelve el mes de la fecha
+    pub fn get_mes(&self) -> u8 {
+        self.mes
+    }
+
+    /// Devuelve el año de la fecha
+    pub fn get_año(&self) -> u16 {
+        self.año
+    }
+
+    /// Devuelve los milisegundos transcurridos entre `inicio` y `fin`,
+    /// o cero si `fin` es anterior a `inicio`
+    pub fn duracion_ms(inicio: &Fecha, fin: &Fecha) -> u64 {
+        fin.tiempo_unix.saturating_sub(inicio.tiempo_unix)
+    }
+
+    /// Devuelve las horas completas transcurridas desde la fecha hasta `other`,
+    /// o cero si `other` es anterior
+    pub fn duracion_en_horas(&self, other: &Fecha) -> u64 {
+        Fecha::duracion_ms(self, other) / 3_600_000
+    }
+
+    /// Determina si la fecha es estrictamente anterior a `other`
+    pub fn es_anterior_a(&self, other: &Fecha) -> bool {
+        self < other
+    }
+}
+
+/// Dos fechas son iguales si representan el mismo tiempo unix
+impl PartialEq for Fecha {
+    fn eq(&self, other: &Self) -> bool {
+        self.tiempo_unix == other.tiempo_unix
+    }
+}
+
+impl Eq for Fecha {}
+
+impl PartialOrd for Fecha {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Las fechas se ordenan cronológicamente según su tiempo unix
+impl Ord for Fecha {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tiempo_unix.cmp(&other.tiempo_unix)
+    }
+}
+
+/// Formato legible de la fecha: `dd/mm/aaaa hh:mm:ss`
+impl Display for Fecha {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:02}/{:02}/{:04} {:02}:{:02}:{:02}",
+            self.dia, self.mes, self.año, self.hora, self.minuto, self.segundo
+        )
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +262,118 @@ mod tests {
         assert_eq!(fecha5.get_tiempo_unix(), 1_717_236_610_000);
     }
 
+    #[test]
+    fn test_desde_tiempo_unix() {
+        assert_eq!(Fecha::desde_tiempo_unix(0), Fecha::new(0, 0, 0, 1, 1, 1970));
+
+        // 29/02/2000 23:59:59, año bisiesto
+        let fecha = Fecha::desde_tiempo_unix(951_868_799_000);
+        assert_eq!(fecha.to_string(), "29/02/2000 23:59:59");
+
+        // 01/06/2024 10:10:10 y medio segundo, se conserva el tiempo unix exacto
+        let fecha = Fecha::desde_tiempo_unix(1_717_236_610_500);
+        assert_eq!(fecha.to_string(), "01/06/2024 10:10:10");
+        assert_eq!(fecha.get_tiempo_unix(), 1_717_236_610_500);
+
+        // 31/12/2023 00:00:00, último día del año
+        let fecha = Fecha::desde_tiempo_unix(1_703_980_800_000);
+        assert_eq!(fecha.to_string(), "31/12/2023 00:00:00");
+    }
+
+    #[test]
+    fn test_orden() {
+        let fecha = Fecha::new(0, 30, 12, 15, 6, 2024);
+        let igual = Fecha::new(0, 30, 12, 15, 6, 2024);
+        let anterior = Fecha::new(59, 29, 12, 15, 6, 2024);
+        let posterior = Fecha::new(0, 0, 0, 1, 1, 2025);
+
+        // Fechas iguales
+        assert_eq!(fecha, igual);
+        assert_eq!(fecha.cmp(&igual), Ordering::Equal);
+        assert!(!fecha.es_anterior_a(&igual));
+
+        // Fecha anterior
+        assert!(anterior < fecha);
+        assert!(anterior.es_anterior_a(&fecha));
+        assert!(!fecha.es_anterior_a(&anterior));
+
+        // Fecha posterior
+        assert!(posterior > fecha);
+        assert!(fecha.es_anterior_a(&posterior));
+        assert!(!posterior.es_anterior_a(&fecha));
+
+        assert_eq!([&posterior, &fecha, &anterior].into_iter().max(), Some(&posterior));
+    }
+
+    #[test]
+    fn test_duracion() {
+        // Elección de tres días y medio
+        let inicio = Fecha::new(0, 0, 0, 20, 5, 2024);
+        let fin = Fecha::new(0, 0, 12, 23, 5, 2024);
+        assert_eq!(Fecha::duracion_ms(&inicio, &fin), 302_400_000);
+        assert_eq!(inicio.duracion_en_horas(&fin), 84);
+
+        // Las horas incompletas no se cuentan
+        let fin2 = Fecha::new(59, 59, 0, 20, 5, 2024);
+        assert_eq!(Fecha::duracion_ms(&inicio, &fin2), 3_599_000);
+        assert_eq!(inicio.duracion_en_horas(&fin2), 0);
+
+        // Fechas invertidas
+        assert_eq!(Fecha::duracion_ms(&fin, &inicio), 0);
+        assert_eq!(fin.duracion_en_horas(&inicio), 0);
+    }
+
+    #[test]
+    fn test_formato() {
+        // Epoch
+        let fecha1 = Fecha::new(0, 0, 0, 1, 1, 1970);
+        assert_eq!(fecha1.to_string(), "01/01/1970 00:00:00");
+
+        // Día, mes y hora de un único dígito
+        let fecha2 = Fecha::new(5, 4, 3, 2, 9, 2024);
+        assert_eq!(fecha2.to_string(), "02/09/2024 03:04:05");
+
+        // Sin relleno necesario
+        let fecha3 = Fecha::new(59, 59, 23, 31, 12, 1999);
+        assert_eq!(fecha3.to_string(), "31/12/1999 23:59:59");
+    }
+
+    #[test]
+    fn test_getters() {
+        let fecha = Fecha::new(5, 4, 3, 2, 9, 2024);
+        assert_eq!(fecha.get_segundo(), 5);
+        assert_eq!(fecha.get_minuto(), 4);
+        assert_eq!(fecha.get_hora(), 3);
+        assert_eq!(fecha.get_dia(), 2);
+        assert_eq!(fecha.get_mes(), 9);
+        assert_eq!(fecha.get_año(), 2024);
+    }
+
+    #[test]
+    fn test_try_new() {
+        // 30/02/2000 00:00:00 -> Invalida
+        assert!(Fecha::try_new(0, 0, 0, 30, 2, 2000).is_err());
+
+        // 29/02/2001 00:00:00 -> Invalida, 2001 no es bisiesto
+        assert!(Fecha::try_new(0, 0, 0, 29, 2, 2001).is_err());
+
+        // 01/13/2000 00:00:00 -> Invalida
+        assert!(Fecha::try_new(0, 0, 0, 1, 13, 2000).is_err());
+
+        // 00/01/2000 00:00:00 -> Invalida
+        assert!(Fecha::try_new(0, 0, 0, 0, 1, 2000).is_err());
+
+        // 01/01/2000 24:00:00 -> Invalida
+        assert!(Fecha::try_new(0, 0, 24, 1, 1, 2000).is_err());
+
+        // 01/01/1969 00:00:00 -> Invalida, previa al epoch
+        assert!(Fecha::try_new(0, 0, 0, 1, 1, 1969).is_err());
+
+        // 29/02/2000 23:59:59 -> Valida
+        let fecha = Fecha::try_new(59, 59, 23, 29, 2, 2000).unwrap();
+        assert_eq!(fecha.get_tiempo_unix(), 951_868_799_000);
+    }
+
     #[test]
     #[should_panic]
     fn test_es_fecha_valida() {
@@ -6,7 +6,7 @@ pub use self::sistema_votacion::SistemaVotacionRef;
 pub mod candidato;
 pub mod eleccion;
 pub mod enums;
-mod fecha;
+pub mod fecha;
 pub mod usuario;
 pub mod votante;
 
@@ -21,16 +21,46 @@ mod sistema_votacion {
     use ink::prelude::{string::String, vec::Vec};
     use ink::storage::{Mapping, StorageVec};
 
-    /// Estructura principal del sistema. Consta del administrador electoral,
+    /// Estructura principal del sistema. Consta del administrador electoral, el conjunto
+    /// de cuentas con privilegios de administrador,
     /// una coleccion de elecciones y dos estructuras de usuarios: ID's de usuarios almacenados por DNI
-    /// y la información personal de todos los usuarios del sistema almacenada por ID
+    /// y la información personal de todos los usuarios del sistema almacenada por ID,
+    /// junto con la cantidad total de usuarios registrados y los ids de las elecciones
+    /// en las que se registró cada usuario
     #[ink(storage)]
     pub struct SistemaVotacion {
         admin: AccountId,
+        admins: Mapping<AccountId, ()>,
+        lista_admins: Vec<AccountId>,
+        admin_pendiente: Option<AccountId>,
         contrato_reportes: Option<AccountId>,
         elecciones: StorageVec<Eleccion>,
         id_usuarios: Mapping<String, AccountId>,
+        total_usuarios: u32,
         usuarios: Mapping<AccountId, Usuario>,
+        elecciones_usuario: Mapping<AccountId, Vec<u32>>,
+    }
+
+    /// Evento emitido cada vez que un votante emite su voto en una elección.
+    /// No incluye el candidato elegido para preservar el secreto del voto.
+    #[ink(event)]
+    pub struct VotoEmitido {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        votante: AccountId,
+    }
+
+    /// Evento emitido cada vez que el administrador aprueba o rechaza a un miembro
+    /// pendiente de una elección, permitiendo reconstruir el historial de aprobaciones.
+    #[ink(event)]
+    pub struct EstadoAprobacionCambiado {
+        #[ink(topic)]
+        id_eleccion: u32,
+        #[ink(topic)]
+        miembro: AccountId,
+        rol: Rol,
+        estado: EstadoAprobacion,
     }
 
     impl SistemaVotacion {
@@ -42,18 +72,38 @@ mod sistema_votacion {
         }
         
         fn new_interno() -> Self {
-            let admin = Self::env().caller();
+            Self::new_con_admin_interno(Self::env().caller())
+        }
+
+        /// Creacion del sistema con un administrador explícito,
+        /// toma como admin el `AccountId` recibido en lugar del de quien crea la instancia.
+        #[ink(constructor)]
+        pub fn new_con_admin(admin: AccountId) -> Self {
+            Self::new_con_admin_interno(admin)
+        }
+
+        fn new_con_admin_interno(admin: AccountId) -> Self {
+            let mut admins = Mapping::new();
+            admins.insert(admin, &());
             Self {
                 admin,
+                admins,
+                lista_admins: ink::prelude::vec![admin],
+                admin_pendiente: Option::None,
                 contrato_reportes: Option::None,
                 elecciones: StorageVec::new(),
                 id_usuarios: Mapping::new(),
+                total_usuarios: 0,
                 usuarios: Mapping::new(),
+                elecciones_usuario: Mapping::new(),
             }
         }
 
         /// Registra un usuario en el sistema de votacion.
         /// Retorna `Error::UsuarioExistente` si el usuario ya existe.
+        /// Retorna `Error::DniInvalido` si el DNI no contiene entre 7 y 8 dígitos numéricos.
+        /// Retorna `Error::UsuarioNoPermitido` si el invocante es un administrador o el
+        /// contrato de reportes.
         #[ink(message)]
         pub fn registrar_usuario(
             &mut self,
@@ -72,26 +122,207 @@ mod sistema_votacion {
         ) -> Result<(), Error> {
             let id = self.env().caller();
 
-            match self.es_admin() {
+            match self.es_admin() || self.es_contrato_reportes() {
                 true => Err(Error::UsuarioNoPermitido),
-                false => {
-                    if self.usuarios.contains(id) || self.id_usuarios.contains(&dni) {
-                        Err(Error::UsuarioExistente)
-                    } else {
-                        let usuario = Usuario::new(nombre, apellido, dni);
-                        self.id_usuarios.insert(usuario.dni.clone(), &id);
-                        self.usuarios.insert(id, &usuario);
-                        Ok(())
-                    }
+                false => self.insertar_usuario(id, nombre, apellido, dni),
+            }
+        }
+
+        /// Permite al administrador registrar varios usuarios en una única llamada, indicando
+        /// para cada uno `(id, nombre, apellido, dni)`. Se aplican las mismas validaciones que
+        /// en `registrar_usuario` y se retorna, para cada usuario, el resultado de su registro,
+        /// que es `Error::UsuarioNoPermitido` si se trata de un administrador o del contrato
+        /// de reportes.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        #[ink(message)]
+        pub fn registrar_usuarios_batch(
+            &mut self,
+            usuarios: Vec<(AccountId, String, String, String)>,
+        ) -> Result<Vec<(AccountId, Result<(), Error>)>, Error> {
+            Self::registrar_usuarios_batch_interno(self, usuarios)
+        }
+
+        fn registrar_usuarios_batch_interno(
+            &mut self,
+            usuarios: Vec<(AccountId, String, String, String)>,
+        ) -> Result<Vec<(AccountId, Result<(), Error>)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            Ok(usuarios
+                .into_iter()
+                .map(|(id, nombre, apellido, dni)| {
+                    let resultado = self
+                        .validar_miembro_aprobable(id)
+                        .and_then(|_| self.insertar_usuario(id, nombre, apellido, dni));
+                    (id, resultado)
+                })
+                .collect())
+        }
+
+        /// Método interno que registra al usuario `id` si su DNI es válido y ni la cuenta
+        /// ni el DNI se encuentran registrados.
+        fn insertar_usuario(
+            &mut self,
+            id: AccountId,
+            nombre: String,
+            apellido: String,
+            dni: String,
+        ) -> Result<(), Error> {
+            if !Usuario::es_dni_valido(&dni) {
+                Err(Error::DniInvalido)
+            } else if self.usuarios.contains(id) || self.id_usuarios.contains(&dni) {
+                Err(Error::UsuarioExistente)
+            } else {
+                let usuario = Usuario::new(nombre, apellido, dni);
+                self.id_usuarios.insert(usuario.dni.clone(), &id);
+                self.usuarios.insert(id, &usuario);
+                self.total_usuarios += 1;
+                Ok(())
+            }
+        }
+
+        /// Retorna la cantidad de usuarios registrados en el sistema.
+        #[ink(message)]
+        pub fn get_total_usuarios(&self) -> u32 {
+            Self::get_total_usuarios_interno(self)
+        }
+
+        fn get_total_usuarios_interno(&self) -> u32 {
+            self.total_usuarios
+        }
+
+        /// Consulta si la cuenta `id` ya se encuentra registrada como usuario del sistema.
+        #[ink(message)]
+        pub fn usuario_existe(&self, id: AccountId) -> bool {
+            Self::usuario_existe_interno(self, id)
+        }
+
+        fn usuario_existe_interno(&self, id: AccountId) -> bool {
+            self.usuarios.contains(id)
+        }
+
+        /// Consulta si el DNI `dni` ya se encuentra registrado por algún usuario del sistema.
+        #[ink(message)]
+        pub fn dni_existe(&self, dni: String) -> bool {
+            Self::dni_existe_interno(self, dni)
+        }
+
+        fn dni_existe_interno(&self, dni: String) -> bool {
+            self.id_usuarios.contains(&dni)
+        }
+
+        /// Consulta si el DNI `dni` tiene un formato válido, entre 7 y 8 dígitos numéricos,
+        /// sin registrar ningún usuario. Es la misma validación aplicada por `registrar_usuario`.
+        #[ink(message)]
+        pub fn dni_es_valido(&self, dni: String) -> bool {
+            Self::dni_es_valido_interno(self, dni)
+        }
+
+        fn dni_es_valido_interno(&self, dni: String) -> bool {
+            Usuario::es_dni_valido(&dni)
+        }
+
+        /// Permite al administrador eliminar el registro del usuario `id`, de forma que su DNI
+        /// pueda volver a registrarse. Se descartan sus solicitudes pendientes de aprobación y
+        /// la lista de elecciones en las que se registró.
+        /// Retorna `Error::PermisosInsuficientes` si un usuario intenta acceder.
+        /// Retorna `Error::UsuarioNoExistente` si `id` no es un usuario registrado.
+        /// Retorna `Error::UsuarioMiembroDeEleccion` si `id` es miembro aprobado de alguna
+        /// elección, sin importar su estado.
+        #[ink(message)]
+        pub fn eliminar_usuario(&mut self, id: AccountId) -> Result<(), Error> {
+            Self::eliminar_usuario_interno(self, id)
+        }
+
+        fn eliminar_usuario_interno(&mut self, id: AccountId) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let Some(usuario) = self.usuarios.get(id) else {
+                return Err(Error::UsuarioNoExistente);
+            };
+
+            let es_miembro_aprobado = (0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .any(|e| e.existe_miembro_aprobado(&id));
+            if es_miembro_aprobado {
+                return Err(Error::UsuarioMiembroDeEleccion);
+            }
+
+            for i in 0..self.elecciones.len() {
+                let Some(mut eleccion) = self.elecciones.get(i) else {
+                    continue;
+                };
+                if eleccion.existe_usuario(&id).is_some() {
+                    let _ = eleccion.rechazar_miembro(&id, &Rol::Candidato);
+                    let _ = eleccion.rechazar_miembro(&id, &Rol::Votante);
+                    self.elecciones.set(i, &eleccion);
                 }
             }
+            self.elecciones_usuario.remove(id);
+
+            self.usuarios.remove(id);
+            self.id_usuarios.remove(usuario.dni);
+            self.total_usuarios -= 1;
+            Ok(())
+        }
+
+        /// Permite al administrador transferir el registro del usuario `viejo` a la cuenta
+        /// `nuevo`, conservando su información personal.
+        /// Retorna `Error::PermisosInsuficientes` si un usuario intenta acceder.
+        /// Retorna `Error::UsuarioNoExistente` si `viejo` no es un usuario registrado.
+        /// Retorna `Error::UsuarioExistente` si `nuevo` ya es un usuario registrado.
+        /// Retorna `Error::UsuarioNoPermitido` si `nuevo` es un administrador o el contrato
+        /// de reportes.
+        /// Retorna `Error::UsuarioMiembroDeEleccion` si `viejo` es miembro, aprobado o
+        /// pendiente, de alguna elección.
+        #[ink(message)]
+        pub fn migrar_usuario(&mut self, viejo: AccountId, nuevo: AccountId) -> Result<(), Error> {
+            Self::migrar_usuario_interno(self, viejo, nuevo)
+        }
+
+        fn migrar_usuario_interno(
+            &mut self,
+            viejo: AccountId,
+            nuevo: AccountId,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let Some(usuario) = self.usuarios.get(viejo) else {
+                return Err(Error::UsuarioNoExistente);
+            };
+            if self.usuarios.contains(nuevo) {
+                return Err(Error::UsuarioExistente);
+            }
+            if self.admins.contains(nuevo) || self.contrato_reportes == Some(nuevo) {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            let es_miembro = (0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .any(|e| e.existe_usuario(&viejo).is_some());
+            if es_miembro {
+                return Err(Error::UsuarioMiembroDeEleccion);
+            }
+            self.usuarios.remove(viejo);
+            self.id_usuarios.insert(usuario.dni.clone(), &nuevo);
+            self.usuarios.insert(nuevo, &usuario);
+            self.elecciones_usuario.remove(viejo);
+            Ok(())
         }
 
         /// Registra un votante o un candidato en una elección determinada.
         ///
         /// Retorna `Error::UsuarioNoExistente` si el usuario no esta registrado.
-        /// Retorna `Error::MiembroExistente` si el usuario ya esta registrado en la votacion.
+        /// Retorna `Error::UsuarioYaEsCandidato` o `Error::UsuarioYaEsVotante` si el usuario ya
+        /// esta registrado en la votacion, según el rol con el que lo hizo. Si la elección
+        /// permite que los candidatos voten, un usuario puede registrarse con ambos roles.
         /// Retorna `Error::VotacionNoExiste` si la votacion no existe.
+        /// Retorna `Error::RegistroCerrado` si el registro de la elección ya cerró.
+        /// Retorna `Error::UsuarioNoPermitido` si el invocante es el contrato de reportes.
         #[ink(message)]
         pub fn registrar_en_eleccion(
             &mut self, 
@@ -108,27 +339,58 @@ mod sistema_votacion {
         ) -> Result<(), Error> {
             let id = self.env().caller();
 
+            if self.es_contrato_reportes() {
+                return Err(Error::UsuarioNoPermitido);
+            }
             if !self.usuarios.contains(id) {
                 return Err(Error::UsuarioNoExistente);
             }
 
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1).as_mut() {
-                if eleccion.existe_usuario(&id) {
-                    Err(Error::MiembroExistente)
-                } else {
-                    let r = eleccion.añadir_miembro(id, rol, self.env().block_timestamp());
-                    if r.is_ok() {
-                        self.elecciones.set(id_eleccion - 1, eleccion); // Necesario ya que no trabajamos con una referencia
-                    }
-                    r
-                }
-            } else {
-                Err(Error::VotacionNoExiste)
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.verificar_registro(&id, &rol)?;
+            if !eleccion.esta_invitado(&id) {
+                return Err(Error::NoInvitado);
             }
+            let r = eleccion.añadir_miembro(id, rol, self.env().block_timestamp());
+            if r.is_ok() {
+                self.elecciones.set(id_eleccion - 1, &eleccion); // Necesario ya que no trabajamos con una referencia
+                self.agregar_eleccion_usuario(id, id_eleccion);
+            }
+            r
+        }
+
+        /// Permite al invocante retirarse de una elección en la que se registró con el `Rol`
+        /// especificado, mientras aún no haya sido aprobado y la elección no haya iniciado.
+        ///
+        /// Retorna `Error::VotacionNoExiste` si la votacion no existe.
+        /// Retorna `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la votacion ya inició.
+        /// Retorna `Error::VotacionCancelada` si la votacion fue cancelada.
+        /// Retorna `Error::VotanteNoExistente` o `Error::CandidatoNoExistente` si el invocante
+        /// no está pendiente de aprobación con ese rol.
+        #[ink(message)]
+        pub fn abandonar_eleccion(&mut self, id_eleccion: u32, rol: Rol) -> Result<(), Error> {
+            Self::abandonar_eleccion_interno(self, id_eleccion, rol)
+        }
+
+        fn abandonar_eleccion_interno(&mut self, id_eleccion: u32, rol: Rol) -> Result<(), Error> {
+            let id = self.env().caller();
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.abandonar(&id, &rol, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            self.quitar_eleccion_usuario(id, id_eleccion);
+            Ok(())
         }
 
         /// Permite al administrador crear una eleccion con los datos correspondientes.
+        /// Retorna `Error::LimiteEleccionesAlcanzado` si no quedan ids disponibles.
+        /// `quorum_minimo` es la cantidad mínima de votos emitidos para que el resultado
+        /// sea válido, puede ser cero si la elección no lo requiere.
+        /// `candidatos_pueden_votar` indica si los candidatos aprobados pueden emitir su voto.
         /// Retorna `Error::PermisosInsuficientes` si un usuario intenta acceder.
+        /// Retorna `Error::PuestoInvalido` si el puesto está vacío o solo contiene espacios.
+        /// Retorna `Error::FechaInvalida` si alguna de las fechas no existe.
+        /// Retorna `Error::FechaFinalizacionInvalida` si la fecha de finalización no es
+        /// posterior a la de inicio.
         #[ink(message)]
         pub fn crear_eleccion(
             &mut self,
@@ -143,6 +405,8 @@ mod sistema_votacion {
             dia_fin: u8,
             mes_fin: u8,
             año_fin: u16,
+            quorum_minimo: u32,
+            candidatos_pueden_votar: bool,
         ) -> Result<u32, Error> {
             Self::crear_eleccion_interno(self, 
                 puesto, 
@@ -155,7 +419,9 @@ mod sistema_votacion {
                 minuto_fin, 
                 dia_fin, 
                 mes_fin, 
-                año_fin)
+                año_fin,
+                quorum_minimo,
+                candidatos_pueden_votar)
         }
         
         fn crear_eleccion_interno(
@@ -171,738 +437,8017 @@ mod sistema_votacion {
             dia_fin: u8,
             mes_fin: u8,
             año_fin: u16,
+            quorum_minimo: u32,
+            candidatos_pueden_votar: bool,
         ) -> Result<u32, Error> {
             if !self.es_admin() {
                 return Err(Error::PermisosInsuficientes);
             }
-            let inicio = Fecha::new(
-                0,
-                minuto_inicio,
+            if puesto.trim().is_empty() {
+                return Err(Error::PuestoInvalido);
+            }
+            let (inicio, fin) = Self::construir_fechas_eleccion(
                 hora_inicio,
+                minuto_inicio,
                 dia_inicio,
                 mes_inicio,
                 año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin,
+            )?;
+
+            let id = self
+                .elecciones
+                .len()
+                .checked_add(1)
+                .ok_or(Error::LimiteEleccionesAlcanzado)?;
+            let eleccion = Eleccion::new(
+                id,
+                puesto,
+                inicio,
+                fin,
+                quorum_minimo,
+                self.env().block_timestamp(),
+                candidatos_pueden_votar,
             );
-            let fin = Fecha::new(0, minuto_fin, hora_fin, dia_fin, mes_fin, año_fin);
-
-            if inicio.get_tiempo_unix() > fin.get_tiempo_unix() {
-                return Err(Error::FechaFinalizacionInvalida);
-            }
-
-            let id: u32 = self.elecciones.len() + 1;
-            let eleccion = Eleccion::new(id, puesto, inicio, fin);
             self.elecciones.push(&eleccion);
             Ok(id)
         }
 
-        /// Permite al administrador ceder sus privilegios a otro usuario cuyo `AccountId` es `id_nuevo_admin`
-        /// Si el usuario que le invoca no es administrador retorna `Error::PermisosInsuficientes`
-        #[ink(message)]
-        pub fn delegar_admin(&mut self, id_nuevo_admin: AccountId) -> Result<(), Error> {
-            Self::delegar_admin_interno(self, id_nuevo_admin)
-        }
-        
-        fn delegar_admin_interno(&mut self, id_nuevo_admin: AccountId) -> Result<(), Error> {
-            if !self.es_admin() {
-                return Err(Error::PermisosInsuficientes);
-            }
-            self.admin = id_nuevo_admin;
-            Ok(())
-        }
-
-        /// Retorna un vector con `AccountId` e informacion de votantes o candidatos,
-        /// según el `Rol` especificado, para elección `id_elección`.
-        ///
-        /// Solo contendrá **usuarios registrados** que no han sido verificados por el administrador para esa
-        /// elección. Éste método no verifica que el usuario exista en el sistema,
-        /// esto ocurre cuando el usuario se registra como votante o candidato.
-        /// Si el invocante no es administrador retorna `Error:PermisosInsuficientes`
-        ///
-        /// # Panics
-        /// Produce panic si el usuario de la elección
-        /// no existe en el sistema.
+        /// Permite verificar las fechas de una elección antes de crearla, aplicando las mismas
+        /// validaciones que `crear_eleccion` sin modificar el estado del contrato.
+        /// Retorna `Error::FechaInvalida` si alguna de las fechas no existe.
+        /// Retorna `Error::FechaFinalizacionInvalida` si la fecha de finalización no es
+        /// posterior a la de inicio.
         #[ink(message)]
-        pub fn consultar_miembros_no_verificados_(
-            &self,
-            id_eleccion: u32,
-            rol: Rol,
-        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
-            Self::consultar_miembros_no_verificados_interno(self, id_eleccion, rol)
-        }
-        
-        fn consultar_miembros_no_verificados_interno(
+        pub fn validar_parametros_eleccion(
             &self,
-            id_eleccion: u32,
-            rol: Rol,
-        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
-            if !self.es_admin() {
-                return Err(Error::PermisosInsuficientes);
-            }
-
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                let id_miembros = eleccion.get_no_verificados(&rol);
-
-                let miembros = id_miembros
-                    .iter()
-                    .map(|id| {
-                        let Some(u) = self.usuarios.get(id) else {
-                            panic!("{}", Error::UsuarioNoExistente);
-                        };
-
-                        (id.clone(), u.clone())
-                    })
-                    .collect();
-
-                Ok(miembros)
-            } else {
-                Err(Error::VotacionNoExiste)
-            }
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<(), Error> {
+            Self::validar_parametros_eleccion_interno(self,
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin)
         }
 
-        /// Retorna un vector con el `AccountId`, nombre y apellido de los candidatos de
-        /// determinada elección que fueron aprobados.
-        ///
-        /// Permite a cualquier miembro registrado y aprobado de una elección
-        /// conocer los candidatos disponibles.
-        ///
-        /// # Panics
-        /// Produce panic si el candidato registrado en la eleccion no
-        /// se encuentra registrado en el sistema.
-        #[ink(message)]
-        pub fn consultar_candidatos_disponibles(
+        fn validar_parametros_eleccion_interno(
             &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, String, String)>, Error> {
-            Self::consultar_candidatos_disponibles_interno(self, id_eleccion)
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<(), Error> {
+            Self::construir_fechas_eleccion(
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin,
+            )
+            .map(|_| ())
         }
-        
-        fn consultar_candidatos_disponibles_interno(
-            &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, String, String)>, Error> {
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                if !self.es_admin() && !eleccion.existe_miembro_aprobado(&self.env().caller()) {
-                    return Err(Error::PermisosInsuficientes);
-                }
-
-                let id_candidatos = eleccion.get_candidatos_verificados();
-
-                let candidatos = id_candidatos
-                    .iter()
-                    .map(|id| {
-                        let Some(u) = self.usuarios.get(id) else {
-                            panic!("{}", Error::UsuarioNoExistente);
-                        };
 
-                        (id.clone(), u.nombre.clone(), u.apellido.clone())
-                    })
-                    .collect();
+        /// Construye las fechas de inicio y finalización de una elección, verificando que
+        /// ambas existan y que la de finalización sea posterior a la de inicio.
+        fn construir_fechas_eleccion(
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<(Fecha, Fecha), Error> {
+            let inicio = Fecha::try_new(
+                0,
+                minuto_inicio,
+                hora_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+            )?;
+            let fin = Fecha::try_new(0, minuto_fin, hora_fin, dia_fin, mes_fin, año_fin)?;
 
-                Ok(candidatos)
-            } else {
-                Err(Error::VotacionNoExiste)
+            if !inicio.es_anterior_a(&fin) {
+                return Err(Error::FechaFinalizacionInvalida);
             }
+            Ok((inicio, fin))
         }
 
-        /// Permite al administrador aprobar o rechazar un miembro de una eleccion, ya sea un `Votante` o `Candidato`.
-        ///
-        /// # Retorno
-        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
-        /// * `Error::CandidatoNoExistente` si el Candidato no existe.
-        /// * `Error::VotanteNoExistente` si el Votante no existe.
-        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// Igual a `crear_eleccion`, pero el registro de miembros cierra en la fecha indicada
+        /// en lugar de al iniciar la elección.
+        /// Retorna `Error::FechaFinRegistroInvalida` si el cierre del registro es posterior
+        /// al inicio de la elección.
         #[ink(message)]
-        pub fn cambiar_estado_aprobacion(
-            &mut self,
-            id_eleccion: u32,
-            id_miembro: AccountId,
-            rol: Rol,
-            estado: EstadoAprobacion,
-        ) -> Result<(), Error> {
-            Self::cambiar_estado_aprobacion_interno(self, id_eleccion, id_miembro, rol, estado)
-        }
-        
-        fn cambiar_estado_aprobacion_interno(
+        pub fn crear_eleccion_con_fin_registro(
             &mut self,
-            id_eleccion: u32,
-            id_miembro: AccountId,
-            rol: Rol,
-            estado: EstadoAprobacion,
-        ) -> Result<(), Error> {
+            puesto: String,
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+            hora_fin_registro: u8,
+            minuto_fin_registro: u8,
+            dia_fin_registro: u8,
+            mes_fin_registro: u8,
+            año_fin_registro: u16,
+            quorum_minimo: u32,
+            candidatos_pueden_votar: bool,
+        ) -> Result<u32, Error> {
+            Self::crear_eleccion_con_fin_registro_interno(self,
+                puesto,
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin,
+                hora_fin_registro,
+                minuto_fin_registro,
+                dia_fin_registro,
+                mes_fin_registro,
+                año_fin_registro,
+                quorum_minimo,
+                candidatos_pueden_votar)
+        }
+
+        fn crear_eleccion_con_fin_registro_interno(
+            &mut self,
+            puesto: String,
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+            hora_fin_registro: u8,
+            minuto_fin_registro: u8,
+            dia_fin_registro: u8,
+            mes_fin_registro: u8,
+            año_fin_registro: u16,
+            quorum_minimo: u32,
+            candidatos_pueden_votar: bool,
+        ) -> Result<u32, Error> {
             if !self.es_admin() {
                 return Err(Error::PermisosInsuficientes);
             }
-
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1).as_mut() {
-                return match eleccion.consultar_estado(self.env().block_timestamp()) {
-                    EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
-                    EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
-                    EstadoDeEleccion::Pendiente => {
-                        let res = match estado {
-                            EstadoAprobacion::Aprobado => {
-                                eleccion.aprobar_miembro(&id_miembro, &rol)
-                            }
-                            EstadoAprobacion::Rechazado => {
-                                eleccion.rechazar_miembro(&id_miembro, &rol)
-                            }
-                        };
-                        self.elecciones.set(id_eleccion - 1, eleccion); // Necesario ya que no trabajamos con una referencia
-                        res
-                    }
-                };
-            } else {
-                Err(Error::VotacionNoExiste)
+            let inicio = Fecha::try_new(
+                0,
+                minuto_inicio,
+                hora_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+            )?;
+            let fin_registro = Fecha::try_new(
+                0,
+                minuto_fin_registro,
+                hora_fin_registro,
+                dia_fin_registro,
+                mes_fin_registro,
+                año_fin_registro,
+            )?;
+            if fin_registro > inicio {
+                return Err(Error::FechaFinRegistroInvalida);
             }
+
+            let id = self.crear_eleccion_interno(
+                puesto,
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin,
+                quorum_minimo,
+                candidatos_pueden_votar,
+            )?;
+            let mut eleccion = self.buscar_eleccion(id)?;
+            eleccion.fin_registro = fin_registro;
+            self.elecciones.set(id - 1, &eleccion);
+            Ok(id)
         }
 
-        /// Recibe el id de una elección y retorna su estado actual.
-        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        /// Permite al administrador crear la segunda vuelta de la elección finalizada de id
+        /// `id_origen`, con las fechas de inicio y cierre indicadas. La nueva elección conserva
+        /// el puesto, la descripción, el quorum mínimo, si los candidatos pueden votar, el margen
+        /// de gracia del registro y el modo por invitación con sus invitados. Cubre un único
+        /// escaño, no admite votos write-in ni pertenece a un grupo.
+        /// Tiene como únicos candidatos aprobados a los dos más votados de la original, sin
+        /// votos y con su partido y propuesta. Los votantes deben registrarse nuevamente.
+        /// Retorna `Error::PermisosInsuficientes` si un usuario intenta acceder.
+        /// Retorna `Error::VotacionNoIniciada` o `Error::VotacionEnCurso` si la elección de
+        /// origen aún no finalizó, y `Error::VotacionCancelada` si fue cancelada.
+        /// Retorna `Error::CandidatosInsuficientes` si la elección de origen tiene menos de
+        /// dos candidatos aprobados.
         #[ink(message)]
-        pub fn consultar_estado(&self, id_eleccion: u32) -> Result<EstadoDeEleccion, Error> {
-            Self::consultar_estado_interno(self, id_eleccion)
+        pub fn crear_segunda_vuelta(
+            &mut self,
+            id_origen: u32,
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<u32, Error> {
+            Self::crear_segunda_vuelta_interno(self,
+                id_origen,
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin)
         }
-        
-        fn consultar_estado_interno(&self, id_eleccion: u32) -> Result<EstadoDeEleccion, Error> {
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                Ok(eleccion.consultar_estado(self.env().block_timestamp()))
-            } else {
-                Err(Error::VotacionNoExiste)
+
+        fn crear_segunda_vuelta_interno(
+            &mut self,
+            id_origen: u32,
+            hora_inicio: u8,
+            minuto_inicio: u8,
+            dia_inicio: u8,
+            mes_inicio: u8,
+            año_inicio: u16,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<u32, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
             }
-        }
+            let origen = self.buscar_eleccion(id_origen)?;
+            let finalistas = origen.get_finalistas(self.env().block_timestamp())?;
 
-        /// Retorna el tiempo actual del bloque.
-        /// Devuelve `Error::PermisosInsuficientes` si no es llamado por el admin.
-        #[ink(message)]
-        pub fn get_block_timestamp(&self) -> Result<u64, Error> {
-            Self::get_block_timestamp_interno(&self)
-        }
+            let id = self.crear_eleccion_interno(
+                origen.get_puesto(),
+                hora_inicio,
+                minuto_inicio,
+                dia_inicio,
+                mes_inicio,
+                año_inicio,
+                hora_fin,
+                minuto_fin,
+                dia_fin,
+                mes_fin,
+                año_fin,
+                origen.quorum_minimo,
+                origen.candidatos_pueden_votar,
+            )?;
+            let mut eleccion = self.buscar_eleccion(id)?;
+            eleccion.descripcion = origen.descripcion.clone();
+            eleccion.gracia_ms = origen.gracia_ms;
+            eleccion.modo_invitacion = origen.modo_invitacion;
+            eleccion.invitados = origen.invitados.clone();
+            eleccion.candidatos_aprobados = finalistas
+                .iter()
+                .map(|finalista| {
+                    let mut candidato = Candidato::new(*finalista);
+                    if let Some(original) = origen
+                        .candidatos_aprobados
+                        .iter()
+                        .find(|c| c.get_account_id() == *finalista)
+                    {
+                        candidato.partido = original.partido.clone();
+                        candidato.propuesta = original.propuesta.clone();
+                    }
+                    candidato
+                })
+                .collect();
+            self.elecciones.set(id - 1, &eleccion);
 
-        pub fn get_block_timestamp_interno(&self) -> Result<u64, Error> {
-            match self.es_admin() {
-                true => Ok(self.env().block_timestamp()),
-                false => Err(Error::PermisosInsuficientes),
+            for finalista in finalistas {
+                self.agregar_eleccion_usuario(finalista, id);
             }
+            Ok(id)
         }
 
-        /// Le permite a un registrado en el sistema votar por un candidato
-        /// `id_candidato` en una elección `id_eleccion`, solo si el usuario
-        /// invocante está aprobado en la misma.
+        /// Permite al administrador modificar la fecha de cierre de una elección que aún no inició.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::FechaInvalida` si la fecha ingresada no existe.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::FechaFinalizacionInvalida` si la nueva fecha de cierre no es posterior al inicio.
         #[ink(message)]
-        pub fn votar(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<(), Error> {
-            Self::votar_interno(self, id_eleccion, id_candidato)
+        pub fn extender_fin(
+            &mut self,
+            id_eleccion: u32,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<(), Error> {
+            Self::extender_fin_interno(self, id_eleccion, hora_fin, minuto_fin, dia_fin, mes_fin, año_fin)
         }
-        
-        fn votar_interno(&mut self, id_eleccion: u32, id_candidato: AccountId) -> Result<(), Error> {
-            if let Some(mut eleccion) = self.elecciones.get(id_eleccion - 1) {
-                eleccion.votar(
-                    self.env().caller(),
-                    id_candidato,
-                    self.env().block_timestamp(),
-                )?;
-                self.elecciones.set(id_eleccion - 1, &eleccion);
-                Ok(())
-            } else {
-                Err(Error::VotacionNoExiste)
+
+        fn extender_fin_interno(
+            &mut self,
+            id_eleccion: u32,
+            hora_fin: u8,
+            minuto_fin: u8,
+            dia_fin: u8,
+            mes_fin: u8,
+            año_fin: u16,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
             }
+            let fin = Fecha::try_new(0, minuto_fin, hora_fin, dia_fin, mes_fin, año_fin)?;
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.extender_fin(fin, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
         }
 
-        /// Método interno que retorna `true` si el invocante del contrato es un administrador;
-        /// `false` en cualquier otro caso
-        fn es_admin(&self) -> bool {
-            self.env().caller() == self.admin
+        /// Permite al administrador finalizar de forma anticipada una elección en curso, ante
+        /// una emergencia, estableciendo su cierre en el momento actual.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotacionNoIniciada` si la Eleccion aún no inició.
+        /// * `Error::VotacionFinalizada` si la Eleccion ya finalizó.
+        #[ink(message)]
+        pub fn finalizar_anticipadamente(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            Self::finalizar_anticipadamente_interno(self, id_eleccion)
         }
 
-        /// Método interno que retorna `true` si el invocante del contrato es el
-        /// contrato de reportes
-        /// `false` en cualquier otro caso
-        fn es_contrato_reportes(&self) -> bool {
-            match self.contrato_reportes {
-                Some(c) => self.env().caller() == c,
-                None => false,
+        fn finalizar_anticipadamente_interno(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
             }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.finalizar_anticipadamente(self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
         }
 
-        /// Retorna `Result<T, E>` con vector de ids e informacion del usuario.
-        /// Si la votacion no existe devuelve `Error::VotacionNoExiste`.
-        /// Si el invocante no es el admin devuelve `Error::PermisosInsuficientes`.
-        ///
-        /// # Panics
-        ///
-        /// Produce panic si un votante de la elección
-        /// no se encuentra registrado en el sistema.
+        /// Permite al administrador proponer a otro usuario cuyo `AccountId` es `id_nuevo_admin`
+        /// para cederle sus privilegios. El administrador actual los conserva hasta que
+        /// el propuesto los acepte mediante `aceptar_admin`.
+        /// Si el usuario que le invoca no es administrador retorna `Error::PermisosInsuficientes`
         #[ink(message)]
-        pub fn consultar_info_votantes_aprobados(
-            &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
-            Self::consultar_info_votantes_aprobados_interno(self, id_eleccion)
+        pub fn delegar_admin(&mut self, id_nuevo_admin: AccountId) -> Result<(), Error> {
+            Self::delegar_admin_interno(self, id_nuevo_admin)
         }
         
-        fn consultar_info_votantes_aprobados_interno(
-            &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+        fn delegar_admin_interno(&mut self, id_nuevo_admin: AccountId) -> Result<(), Error> {
             if !self.es_admin() {
                 return Err(Error::PermisosInsuficientes);
             }
-            if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                let votantes = eleccion
-                    .votantes_aprobados
-                    .iter()
-                    .map(|v| {
-                        let Some(u) = self.usuarios.get(v.id) else {
-                            panic!("{}", Error::UsuarioNoExistente);
-                        };
-
-                        (v.id.clone(), u.clone())
-                    })
-                    .collect();
-
-                Ok(votantes)
-            } else {
-                Err(Error::VotacionNoExiste)
-            }
+            self.admin_pendiente = Some(id_nuevo_admin);
+            Ok(())
         }
-    }
 
-    // Reportes
-    impl SistemaVotacion {
-        /// Retorna `Result<T, E>` con vector de `Votante` o `Error` en caso
-        /// de que la votacion no exista o que quien le invoca no sea el
-        ///contrato de reportes
+        /// Permite al usuario propuesto mediante `delegar_admin` aceptar los privilegios
+        /// de administrador. El administrador electoral anterior pierde sus privilegios.
+        /// Si el invocante no es el administrador propuesto retorna `Error::PermisosInsuficientes`
+        /// Si el invocante es el contrato de reportes retorna `Error::ContratoReportesInvalido`
         #[ink(message)]
-        pub fn get_votantes_aprobados(&self, id_eleccion: u32) -> Result<Vec<Votante>, Error> {
-            Self::get_votantes_aprobados_interno(self, id_eleccion)
+        pub fn aceptar_admin(&mut self) -> Result<(), Error> {
+            Self::aceptar_admin_interno(self)
         }
-        
-        fn get_votantes_aprobados_interno(&self, id_eleccion: u32) -> Result<Vec<Votante>, Error> {
-            if self.es_contrato_reportes() {
-                if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                    Ok(eleccion.votantes_aprobados)
-                } else {
-                    Err(Error::VotacionNoExiste)
+
+        fn aceptar_admin_interno(&mut self) -> Result<(), Error> {
+            match self.admin_pendiente {
+                Some(id) if id == self.env().caller() => {
+                    if self.contrato_reportes == Some(id) {
+                        return Err(Error::ContratoReportesInvalido);
+                    }
+                    if self.admins.contains(self.admin) {
+                        self.admins.remove(self.admin);
+                        let anterior = self.admin;
+                        self.lista_admins.retain(|a| *a != anterior);
+                    }
+                    if !self.admins.contains(id) {
+                        self.admins.insert(id, &());
+                        self.lista_admins.push(id);
+                    }
+                    self.admin = id;
+                    self.admin_pendiente = None;
+                    Ok(())
                 }
-            } else {
-                Err(Error::PermisosInsuficientes)
+                _ => Err(Error::PermisosInsuficientes),
             }
         }
 
-        // Obtener un usuario cuyo AccountId es `account_id`
-        // Devuelve `Err(Error::PermisosInsuficientes)` si el invocante no
-        // es el contrato de reportes
-        // Devuelve Error::UsuarioNoExistente si no existe el usuario
-        // de AccountId `account_id`
+        /// Retorna el `AccountId` del administrador principal del sistema, es decir, el que
+        /// creó el contrato o el último que aceptó los privilegios mediante `aceptar_admin`.
         #[ink(message)]
-        pub fn get_usuarios(&self, account_id: AccountId) -> Result<Usuario, Error> {
-            Self::get_usuarios_interno(self, account_id)
+        pub fn get_admin(&self) -> AccountId {
+            Self::get_admin_interno(self)
         }
-        
-        fn get_usuarios_interno(&self, account_id: AccountId) -> Result<Usuario, Error> {
-            if self.es_contrato_reportes() {
-                if let Some(id) = self.usuarios.get(account_id) {
-                    Ok(id)
-                } else {
-                    Err(Error::UsuarioNoExistente)
-                }
-            } else {
-                Err(Error::PermisosInsuficientes)
-            }
+
+        fn get_admin_interno(&self) -> AccountId {
+            self.admin
         }
 
-        /// # Reportes
-        /// Retorna los candidatos aprobados en la elección de id `id_eleccion` asociados a su voto.
-        /// Utiliza el `AccountId` asociado a los candidatos en la elección para buscar los
-        /// usuarios registrados en el sistema.
-        /// Verifica el estado de la elección y si el invocante es el contrato de reportes
+        /// Retorna el `AccountId` de todas las cuentas con privilegios de administrador,
+        /// en el orden en que los obtuvieron.
         #[ink(message)]
-        pub fn get_candidatos(
-            &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<Candidato>, Error> {
-            Self::get_candidatos_interno(self, id_eleccion)
+        pub fn get_admins(&self) -> Vec<AccountId> {
+            Self::get_admins_interno(self)
         }
-        
-        fn get_candidatos_interno(
-            &self,
-            id_eleccion: u32,
-        ) -> Result<Vec<Candidato>, Error> {
-            if !self.es_contrato_reportes() {
-                Err(Error::PermisosInsuficientes)
-            } else if let Some(eleccion) = self.elecciones.get(id_eleccion - 1) {
-                match eleccion.consultar_estado(self.env().block_timestamp()) {
-                    EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
-                    EstadoDeEleccion::EnCurso => Err(Error::VotacionEnCurso),
-                    EstadoDeEleccion::Finalizada => Ok(eleccion.candidatos_aprobados),
-                }
-            } else {
-                Err(Error::VotacionNoExiste)
-            }
+
+        fn get_admins_interno(&self) -> Vec<AccountId> {
+            self.lista_admins.clone()
         }
 
-        /// Permite al administrador establecer el AccountId del contrato que podrá acceder
-        /// a una serie de métodos que obtienen información de una elección
+        /// Permite a un administrador otorgar privilegios de administrador a `nuevo`.
+        /// Si el invocante no es administrador retorna `Error::PermisosInsuficientes`
+        /// Si `nuevo` ya es administrador retorna `Error::AdminExistente`
+        /// Si `nuevo` es el contrato de reportes retorna `Error::ContratoReportesInvalido`
         #[ink(message)]
-        pub fn establecer_contrato_reportes(&mut self, account_id: AccountId) -> Result<(), Error> {
-            Self::establecer_contrato_reportes_interno(self, account_id)
+        pub fn agregar_admin(&mut self, nuevo: AccountId) -> Result<(), Error> {
+            Self::agregar_admin_interno(self, nuevo)
         }
-        
-        fn establecer_contrato_reportes_interno(&mut self, account_id: AccountId) -> Result<(), Error> {
+
+        fn agregar_admin_interno(&mut self, nuevo: AccountId) -> Result<(), Error> {
             if !self.es_admin() {
                 return Err(Error::PermisosInsuficientes);
             }
-            self.contrato_reportes = Some(account_id);
-            Ok(()) //exitoso
+            if self.admins.contains(nuevo) {
+                return Err(Error::AdminExistente);
+            }
+            if self.contrato_reportes == Some(nuevo) {
+                return Err(Error::ContratoReportesInvalido);
+            }
+            self.admins.insert(nuevo, &());
+            self.lista_admins.push(nuevo);
+            Ok(())
         }
 
-        /// Recibe el id de una votacion y retorna su estado actual.
-        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
-        /// Devuelve `Error::PermisosInsuficientes` si el invocanto no es
-        /// el contrato de reportes
+        /// Permite a un administrador quitarle los privilegios de administrador a `id`.
+        /// Si el invocante no es administrador retorna `Error::PermisosInsuficientes`
+        /// Si `id` no es administrador retorna `Error::AdminNoExistente`
+        /// Si `id` es el único administrador retorna `Error::UltimoAdmin`
+        /// Si `id` es el administrador principal, este pasa a ser el administrador restante
+        /// más antiguo.
         #[ink(message)]
-        pub fn get_estado_eleccion(&self, id_votacion: u32) -> Result<EstadoDeEleccion, Error> {
-            Self::get_estado_eleccion_interno(self, id_votacion)
+        pub fn remover_admin(&mut self, id: AccountId) -> Result<(), Error> {
+            Self::remover_admin_interno(self, id)
         }
 
-        pub fn get_estado_eleccion_interno(&self, id_votacion: u32) -> Result<EstadoDeEleccion, Error> {
-            if self.es_contrato_reportes() {
-                self.consultar_estado(id_votacion)
-            } else {
-                Err(Error::PermisosInsuficientes)
+        fn remover_admin_interno(&mut self, id: AccountId) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            if !self.admins.contains(id) {
+                return Err(Error::AdminNoExistente);
+            }
+            if self.lista_admins.len() <= 1 {
+                return Err(Error::UltimoAdmin);
             }
+            self.admins.remove(id);
+            self.lista_admins.retain(|a| *a != id);
+            if self.admin == id {
+                self.admin = self.lista_admins[0];
+            }
+            Ok(())
         }
 
+        /// Permite al administrador cancelar una elección que aún no ha iniciado.
+        /// La elección conserva su id, pero no admite registros ni votos.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion ya fue cancelada.
         #[ink(message)]
-        pub fn get_hash(&self) -> Hash {
-            self.env().own_code_hash().unwrap()
+        pub fn cancelar_eleccion(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            Self::cancelar_eleccion_interno(self, id_eleccion)
         }
 
-        #[ink(message)]
-        pub fn get_account_id(&self) -> AccountId {
-            self.env().account_id()
+        fn cancelar_eleccion_interno(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.cancelar(self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
         }
-    }
 
-    impl Default for SistemaVotacion {
-        fn default() -> Self {
-            Self::new()
+        /// Permite al administrador establecer el peso del voto del votante `id_votante` en la
+        /// elección `id_eleccion`, antes de que esta inicie. Por defecto cada voto pesa 1.
+        /// El peso solo afecta los votos recibidos por el candidato elegido, la participación
+        /// y el quorum se calculan por cantidad de votantes.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotanteNoExistente` si el votante no está registrado en la Eleccion.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_peso_votante(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            peso: u32,
+        ) -> Result<(), Error> {
+            Self::set_peso_votante_interno(self, id_eleccion, id_votante, peso)
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use ink::{
-            env::{DefaultEnvironment, Environment},
-            primitives::AccountId,
-        };
+        fn set_peso_votante_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_votante: AccountId,
+            peso: u32,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-        use crate::candidato::Candidato;
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_peso_votante(&id_votante, peso, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-        use super::*;
+        /// Permite al administrador establecer, antes del inicio de la elección `id_eleccion`, un
+        /// margen de gracia de `gracia_ms` milisegundos durante el cual aún se aceptan registros
+        /// luego del inicio. Por defecto es 0. No aplica si el registro cierra antes del inicio.
+        /// Durante el margen el administrador también puede aprobar o rechazar a los miembros
+        /// pendientes, de forma que quienes se registren en él puedan votar.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_gracia_registro(&mut self, id_eleccion: u32, gracia_ms: u64) -> Result<(), Error> {
+            Self::set_gracia_registro_interno(self, id_eleccion, gracia_ms)
+        }
 
-        /* Helpers */
+        fn set_gracia_registro_interno(
+            &mut self,
+            id_eleccion: u32,
+            gracia_ms: u64,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-        struct ContractEnv {
-            contract: SistemaVotacion,
-            contract_id: AccountId,
-            accounts: ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment>,
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_gracia_registro(gracia_ms, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
         }
 
-        impl Default for ContractEnv {
-            fn default() -> Self {
-                Self {
-                    contract: SistemaVotacion::new_interno(),
-                    contract_id: ink::env::account_id::<ink::env::DefaultEnvironment>(),
-                    // Por defecto se superpone el `AccountId` del contrato
-                    // con el de alice, por eso esto es necesario.
-                    accounts: ink::env::test::DefaultAccounts {
-                        alice: <DefaultEnvironment as Environment>::AccountId::from([0xFF; 32]),
-                        bob: <DefaultEnvironment as Environment>::AccountId::from([0xFE; 32]),
-                        charlie: <DefaultEnvironment as Environment>::AccountId::from([0xFD; 32]),
-                        django: <DefaultEnvironment as Environment>::AccountId::from([0xFC; 32]),
-                        eve: <DefaultEnvironment as Environment>::AccountId::from([0xFB; 32]),
-                        frank: <DefaultEnvironment as Environment>::AccountId::from([0xFA; 32]),
-                    },
-                }
-            }
+        /// Permite al administrador establecer la cantidad de `escaños` a cubrir en la elección
+        /// `id_eleccion`, antes de que esta inicie. Por defecto se cubre un único escaño.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::EscañosInvalidos` si `escaños` es cero.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_escaños(&mut self, id_eleccion: u32, escaños: u32) -> Result<(), Error> {
+            Self::set_escaños_interno(self, id_eleccion, escaños)
         }
 
-        impl ContractEnv {
-            // Retorna un `ContractEnv` con 4 usuarios registrados en el sistema:
-            // Alice, Bob, Charlie y Django.
-            // (No está registrada eve, se utiliza para testear permisos)
-            // El administrador es de id `contract_id`, no se delegaron los privilegios.
-            fn new_inicializado() -> Self {
-                let mut env = ContractEnv::default();
-                ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+        fn set_escaños_interno(&mut self, id_eleccion: u32, escaños: u32) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-                // Registrar a Alice
-                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Alice"),
-                        String::from("A"),
-                        String::from("11111111"),
-                    )
-                    .unwrap();
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_escaños(escaños, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-                // Registrar a Bob
-                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Bob"),
-                        String::from("B"),
-                        String::from("22222222"),
-                    )
-                    .unwrap();
+        /// Permite al administrador establecer una `descripcion` de la elección `id_eleccion`,
+        /// más extensa que su puesto, antes de que esta inicie.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::DescripcionDemasiadoLarga` si la descripción supera los 512 bytes.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_descripcion(&mut self, id_eleccion: u32, descripcion: String) -> Result<(), Error> {
+            Self::set_descripcion_interno(self, id_eleccion, descripcion)
+        }
 
-                // Registrar a Charlie
-                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Charlie"),
-                        String::from("C"),
-                        String::from("33333333"),
-                    )
-                    .unwrap();
+        fn set_descripcion_interno(
+            &mut self,
+            id_eleccion: u32,
+            descripcion: String,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-                // Registrar a Django
-                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Django"),
-                        String::from("D"),
-                        String::from("44444444"),
-                    )
-                    .unwrap();
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_descripcion(descripcion, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-                env
-            }
+        /// Permite al administrador habilitar o deshabilitar los votos write-in en la elección
+        /// `id_eleccion`, antes de que esta inicie. Con ellos habilitados, los votantes aprobados
+        /// pueden votar a otro votante aprobado aunque no sea candidato. Por defecto no se permiten.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_permite_write_in(&mut self, id_eleccion: u32, permite: bool) -> Result<(), Error> {
+            Self::set_permite_write_in_interno(self, id_eleccion, permite)
         }
 
-        /* Tests */
+        fn set_permite_write_in_interno(
+            &mut self,
+            id_eleccion: u32,
+            permite: bool,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-        #[ink::test]
-        fn probar_registro_sistema() {
-            let mut env = ContractEnv::default();
-            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_permite_write_in(permite, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-            // Bob como invocante del contrato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+        /// Permite al administrador habilitar o deshabilitar el modo por invitación en la elección
+        /// `id_eleccion`, antes de que esta inicie. Con él habilitado, solo los usuarios invitados
+        /// mediante `invitar` pueden registrarse. Por defecto está deshabilitado.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_modo_invitacion(&mut self, id_eleccion: u32, activo: bool) -> Result<(), Error> {
+            Self::set_modo_invitacion_interno(self, id_eleccion, activo)
+        }
 
-            // Registrar a bob en el sistema
-            assert!(env
-                .contract
-                .registrar_usuario_interno(
-                    String::from("Bob"),
-                    String::from(""),
-                    String::from("22222222")
-                )
-                .is_ok());
+        fn set_modo_invitacion_interno(
+            &mut self,
+            id_eleccion: u32,
+            activo: bool,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-            // El mismo AccountId intenta registrarse de nuevo, no debe poder
-            assert_eq!(
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Alice"),
-                        String::from(""),
-                        String::from("11111111")
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::UsuarioExistente.to_string()
-            );
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_modo_invitacion(activo, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-            // Eve como invocante del contrato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
-            // El mismo dni intenta registrase de nuevo, no debe poder
-            assert_eq!(
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Eve"),
-                        String::from(""),
-                        String::from("22222222")
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::UsuarioExistente.to_string()
-            );
+        /// Permite al administrador invitar a las cuentas de `invitados` a registrarse en la
+        /// elección `id_eleccion`, antes de que esta inicie.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn invitar(&mut self, id_eleccion: u32, invitados: Vec<AccountId>) -> Result<(), Error> {
+            Self::invitar_interno(self, id_eleccion, invitados)
         }
 
-        #[ink::test]
-        fn probar_es_admin() {
-            let env = ContractEnv::default();
-            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+        fn invitar_interno(
+            &mut self,
+            id_eleccion: u32,
+            invitados: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-            // La cuenta invocante es admin
-            assert!(env.contract.es_admin());
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.invitar(invitados, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-            // Eve no es admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
-            assert!(!env.contract.es_admin());
+        /// Permite al administrador devolver a todos los miembros aprobados de la elección de id
+        /// `id_eleccion` al estado pendiente de aprobación, antes de que esta inicie.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn resetear_aprobaciones(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            Self::resetear_aprobaciones_interno(self, id_eleccion)
         }
 
-        #[ink::test]
-        fn probar_delegar_admin() {
-            let mut env = ContractEnv::default();
-            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+        fn resetear_aprobaciones_interno(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
 
-            // La cuenta que crea el contrato le cede los privilegios a Alice
-            assert!(env.contract.delegar_admin_interno(env.accounts.alice).is_ok());
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.resetear_aprobaciones(self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
 
-            // Alice prueba si es admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(env.contract.es_admin());
+        /// Permite al administrador revocar la aprobación del miembro `id_miembro` con el `Rol`
+        /// indicado en la elección de id `id_eleccion`, quitándolo de la misma antes de que inicie.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotanteNoExistente` o `Error::CandidatoNoExistente` si el miembro no está
+        /// aprobado con ese rol.
+        #[ink(message)]
+        pub fn revocar_aprobacion(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+        ) -> Result<(), Error> {
+            Self::revocar_aprobacion_interno(self, id_eleccion, id_miembro, rol)
+        }
+
+        fn revocar_aprobacion_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.revocar_aprobacion(&id_miembro, &rol, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            self.quitar_eleccion_usuario(id_miembro, id_eleccion);
+            Ok(())
+        }
+
+        /// Permite al administrador verificar, antes de su inicio, que la elección de id
+        /// `id_eleccion` cuente con al menos dos candidatos aprobados.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::CandidatosInsuficientes` si hay menos de dos candidatos aprobados.
+        #[ink(message)]
+        pub fn validar_eleccion_lista(&self, id_eleccion: u32) -> Result<(), Error> {
+            Self::validar_eleccion_lista_interno(self, id_eleccion)
+        }
+
+        fn validar_eleccion_lista_interno(&self, id_eleccion: u32) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.validar_lista(self.env().block_timestamp())
+        }
+
+        /// Permite al administrador consultar la cantidad de miembros de la elección de id
+        /// `id_eleccion`, en el orden (votantes pendientes, votantes aprobados,
+        /// candidatos pendientes, candidatos aprobados).
+        /// Retorna `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// Retorna `Error::VotacionNoExiste` si la votacion no existe.
+        #[ink(message)]
+        pub fn get_conteos(&self, id_eleccion: u32) -> Result<(u32, u32, u32, u32), Error> {
+            Self::get_conteos_interno(self, id_eleccion)
+        }
+
+        fn get_conteos_interno(&self, id_eleccion: u32) -> Result<(u32, u32, u32, u32), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok(eleccion.get_conteos())
+        }
+
+        /// Permite al administrador consultar, incluso mientras la elección está en curso,
+        /// cuántos votantes aprobados ya emitieron su voto. No revela a quién votaron.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotacionNoIniciada` si la Eleccion aún no inició.
+        #[ink(message)]
+        pub fn votos_emitidos(&self, id_eleccion: u32) -> Result<u32, Error> {
+            Self::votos_emitidos_interno(self, id_eleccion)
+        }
+
+        fn votos_emitidos_interno(&self, id_eleccion: u32) -> Result<u32, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if eleccion.cancelada {
+                return Err(Error::VotacionCancelada);
+            }
+            match eleccion.consultar_estado(self.env().block_timestamp()) {
+                EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+                EstadoDeEleccion::EnCurso | EstadoDeEleccion::Finalizada => {
+                    Ok(eleccion.get_cantidad_votos())
+                }
+            }
+        }
+
+        /// Permite al administrador consultar la cantidad total de miembros pendientes de
+        /// aprobación, votantes y candidatos, sumando las elecciones que aún admiten
+        /// aprobaciones. No se cuentan las elecciones canceladas, las finalizadas ni las que
+        /// ya iniciaron y vencieron su margen de gracia.
+        /// Retorna `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        #[ink(message)]
+        pub fn total_pendientes(&self) -> Result<u32, Error> {
+            Self::total_pendientes_interno(self)
+        }
+
+        fn total_pendientes_interno(&self) -> Result<u32, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let tiempo = self.env().block_timestamp();
+            Ok((0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .filter(|e| e.permite_aprobacion(tiempo).is_ok())
+                .map(|e| {
+                    let (votantes, _, candidatos, _) = e.get_conteos();
+                    votantes + candidatos
+                })
+                .sum())
+        }
+
+        /// Retorna un vector con `AccountId` e informacion de votantes o candidatos,
+        /// según el `Rol` especificado, para elección `id_elección`.
+        ///
+        /// Solo contendrá **usuarios registrados** que no han sido verificados por el administrador para esa
+        /// elección. Éste método no verifica que el usuario exista en el sistema,
+        /// esto ocurre cuando el usuario se registra como votante o candidato.
+        /// Si el invocante no es administrador retorna `Error:PermisosInsuficientes`
+        /// Si algún miembro de la elección no existe en el sistema retorna
+        /// `Error::UsuarioNoExistente`
+        #[ink(message)]
+        pub fn consultar_miembros_no_verificados_(
+            &self,
+            id_eleccion: u32,
+            rol: Rol,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            Self::consultar_miembros_no_verificados_interno(self, id_eleccion, rol)
+        }
+        
+        fn consultar_miembros_no_verificados_interno(
+            &self,
+            id_eleccion: u32,
+            rol: Rol,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            let id_miembros = eleccion.get_no_verificados(&rol);
+
+            id_miembros
+                .iter()
+                .map(|id| match self.usuarios.get(id) {
+                    Some(u) => Ok((*id, u)),
+                    None => Err(Error::UsuarioNoExistente),
+                })
+                .collect()
+        }
+
+        /// Retorna una página de a lo sumo `limit` miembros no verificados de la elección
+        /// `id_eleccion` según el `Rol` especificado, comenzando desde la posición `offset`.
+        /// Si `offset` excede la cantidad de miembros no verificados se retorna un vector vacío.
+        /// Los errores son los mismos que los de `consultar_miembros_no_verificados_`.
+        #[ink(message)]
+        pub fn consultar_miembros_no_verificados_pagina(
+            &self,
+            id_eleccion: u32,
+            rol: Rol,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            Self::consultar_miembros_no_verificados_pagina_interno(self, id_eleccion, rol, offset, limit)
+        }
+
+        fn consultar_miembros_no_verificados_pagina_interno(
+            &self,
+            id_eleccion: u32,
+            rol: Rol,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            let id_miembros = eleccion.get_no_verificados(&rol);
+
+            id_miembros
+                .iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|id| match self.usuarios.get(id) {
+                    Some(u) => Ok((*id, u)),
+                    None => Err(Error::UsuarioNoExistente),
+                })
+                .collect()
+        }
+
+        /// Retorna un vector con el `AccountId`, nombre y apellido de los candidatos de
+        /// determinada elección que fueron aprobados.
+        ///
+        /// Permite a cualquier miembro registrado y aprobado de una elección
+        /// conocer los candidatos disponibles.
+        ///
+        /// Retorna `Error::UsuarioNoExistente` si el candidato registrado en la eleccion no
+        /// se encuentra registrado en el sistema.
+        #[ink(message)]
+        pub fn consultar_candidatos_disponibles(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, String, String)>, Error> {
+            Self::consultar_candidatos_disponibles_interno(self, id_eleccion)
+        }
+        
+        fn consultar_candidatos_disponibles_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, String, String)>, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if !self.es_admin() && !eleccion.existe_miembro_aprobado(&self.env().caller()) {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let id_candidatos = eleccion.get_candidatos_verificados();
+
+            id_candidatos
+                .iter()
+                .map(|id| match self.usuarios.get(id) {
+                    Some(u) => Ok((*id, u.nombre, u.apellido)),
+                    None => Err(Error::UsuarioNoExistente),
+                })
+                .collect()
+        }
+
+        /// Retorna un vector con el `AccountId`, partido y propuesta de los candidatos
+        /// aprobados de determinada elección, de forma que puedan mostrarse en la boleta.
+        ///
+        /// Permite a cualquier miembro registrado y aprobado de una elección conocer los
+        /// perfiles de los candidatos.
+        #[ink(message)]
+        pub fn get_perfiles_candidatos(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, Option<String>, Option<String>)>, Error> {
+            Self::get_perfiles_candidatos_interno(self, id_eleccion)
+        }
+
+        fn get_perfiles_candidatos_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, Option<String>, Option<String>)>, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if !self.es_admin() && !eleccion.existe_miembro_aprobado(&self.env().caller()) {
+                return Err(Error::PermisosInsuficientes);
+            }
+            Ok(eleccion.get_perfiles_candidatos())
+        }
+
+        /// Retorna un vector con el `AccountId` y el orden de los candidatos aprobados de
+        /// determinada elección, ordenados según su posición en la boleta.
+        ///
+        /// Permite a cualquier miembro registrado y aprobado de una elección conocer la boleta.
+        #[ink(message)]
+        pub fn get_candidatos_ordenados(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            Self::get_candidatos_ordenados_interno(self, id_eleccion)
+        }
+
+        fn get_candidatos_ordenados_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if !self.es_admin() && !eleccion.existe_miembro_aprobado(&self.env().caller()) {
+                return Err(Error::PermisosInsuficientes);
+            }
+            Ok(eleccion.get_candidatos_ordenados())
+        }
+
+        /// Retorna la boleta de la elección `id_eleccion`: el `AccountId` y la información de
+        /// usuario de cada candidato aprobado, ordenados según su posición en la boleta.
+        /// No incluye los votos de los candidatos.
+        ///
+        /// Puede consultarla cualquier cuenta, pero solo mientras la elección está en curso.
+        /// Retorna `Error::VotacionNoExiste` si la votacion no existe.
+        /// Retorna `Error::VotacionCancelada` si la votacion fue cancelada.
+        /// Retorna `Error::VotacionNoIniciada` o `Error::VotacionFinalizada` si la votacion
+        /// no está en curso.
+        /// Retorna `Error::UsuarioNoExistente` si algún candidato no se encuentra registrado
+        /// en el sistema.
+        #[ink(message)]
+        pub fn get_boleta(&self, id_eleccion: u32) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            Self::get_boleta_interno(self, id_eleccion)
+        }
+
+        fn get_boleta_interno(&self, id_eleccion: u32) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if eleccion.cancelada {
+                return Err(Error::VotacionCancelada);
+            }
+            match eleccion.consultar_estado(self.env().block_timestamp()) {
+                EstadoDeEleccion::Pendiente => Err(Error::VotacionNoIniciada),
+                EstadoDeEleccion::Finalizada => Err(Error::VotacionFinalizada),
+                EstadoDeEleccion::EnCurso => eleccion
+                    .get_candidatos_ordenados()
+                    .into_iter()
+                    .map(|(id, _)| match self.usuarios.get(id) {
+                        Some(u) => Ok((id, u)),
+                        None => Err(Error::UsuarioNoExistente),
+                    })
+                    .collect(),
+            }
+        }
+
+        /// Permite al administrador establecer la posición `orden` en la boleta del candidato
+        /// `id_candidato`, antes de que la elección inicie.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::CandidatoNoExistente` si el Candidato no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_orden_candidato(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            orden: u32,
+        ) -> Result<(), Error> {
+            Self::set_orden_candidato_interno(self, id_eleccion, id_candidato, orden)
+        }
+
+        fn set_orden_candidato_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            orden: u32,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_orden_candidato(&id_candidato, orden, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
+
+        /// Permite a un candidato, pendiente o aprobado, establecer el `partido` al que
+        /// pertenece y su `propuesta` en la elección `id_eleccion`, antes de que esta inicie.
+        /// Retorna error si:
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::CandidatoNoExistente` si el invocante no es candidato de la Eleccion.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_perfil_candidato(
+            &mut self,
+            id_eleccion: u32,
+            partido: Option<String>,
+            propuesta: Option<String>,
+        ) -> Result<(), Error> {
+            Self::set_perfil_candidato_interno(self, id_eleccion, partido, propuesta)
+        }
+
+        fn set_perfil_candidato_interno(
+            &mut self,
+            id_eleccion: u32,
+            partido: Option<String>,
+            propuesta: Option<String>,
+        ) -> Result<(), Error> {
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_perfil_candidato(
+                &self.env().caller(),
+                partido,
+                propuesta,
+                self.env().block_timestamp(),
+            )?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
+
+        /// Permite al administrador aprobar o rechazar un miembro de una eleccion, ya sea un `Votante` o `Candidato`.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::CandidatoNoExistente` si el Candidato no existe.
+        /// * `Error::VotanteNoExistente` si el Votante no existe.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::UsuarioNoPermitido` si se intenta aprobar a un administrador o al contrato
+        /// de reportes.
+        #[ink(message)]
+        pub fn cambiar_estado_aprobacion(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+            estado: EstadoAprobacion,
+        ) -> Result<(), Error> {
+            Self::cambiar_estado_aprobacion_interno(self, id_eleccion, id_miembro, rol, estado)
+        }
+        
+        fn cambiar_estado_aprobacion_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+            estado: EstadoAprobacion,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.permite_aprobacion(self.env().block_timestamp())?;
+            let res = match estado {
+                EstadoAprobacion::Aprobado => self
+                    .validar_miembro_aprobable(id_miembro)
+                    .and_then(|_| eleccion.aprobar_miembro(&id_miembro, &rol)),
+                EstadoAprobacion::Rechazado => eleccion.rechazar_miembro(&id_miembro, &rol),
+            };
+            self.elecciones.set(id_eleccion - 1, &eleccion); // Necesario ya que no trabajamos con una referencia
+            if res.is_ok() {
+                if estado == EstadoAprobacion::Rechazado {
+                    self.quitar_eleccion_usuario(id_miembro, id_eleccion);
+                }
+                self.env().emit_event(EstadoAprobacionCambiado {
+                    id_eleccion,
+                    miembro: id_miembro,
+                    rol,
+                    estado,
+                });
+            }
+            res
+        }
+
+        /// Permite al administrador aprobar varios miembros pendientes de una eleccion en una
+        /// única llamada. Retorna, para cada miembro, el resultado de su aprobación, que es
+        /// `Error::UsuarioNoPermitido` si se trata de un administrador o del contrato de reportes.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotacionEnCurso` si la Eleccion ya inició y venció su margen de gracia.
+        /// * `Error::VotacionFinalizada` si la Eleccion finalizó.
+        #[ink(message)]
+        pub fn aprobar_miembros_batch(
+            &mut self,
+            id_eleccion: u32,
+            miembros: Vec<(AccountId, Rol)>,
+        ) -> Result<Vec<(AccountId, Result<(), Error>)>, Error> {
+            Self::aprobar_miembros_batch_interno(self, id_eleccion, miembros)
+        }
+
+        fn aprobar_miembros_batch_interno(
+            &mut self,
+            id_eleccion: u32,
+            miembros: Vec<(AccountId, Rol)>,
+        ) -> Result<Vec<(AccountId, Result<(), Error>)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.permite_aprobacion(self.env().block_timestamp())?;
+            let resultados = miembros
+                .into_iter()
+                .map(|(id, rol)| {
+                    let resultado = self
+                        .validar_miembro_aprobable(id)
+                        .and_then(|_| eleccion.aprobar_miembro(&id, &rol));
+                    if resultado.is_ok() {
+                        self.env().emit_event(EstadoAprobacionCambiado {
+                            id_eleccion,
+                            miembro: id,
+                            rol,
+                            estado: EstadoAprobacion::Aprobado,
+                        });
+                    }
+                    (id, resultado)
+                })
+                .collect();
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(resultados)
+        }
+
+        /// Permite al administrador registrar a un usuario en una elección que aún no inició
+        /// y aprobarlo en una única llamada, útil en elecciones internas donde el administrador
+        /// controla a todos los miembros.
+        ///
+        /// # Retorno
+        /// * `Error::PermisosInsuficientes` si un Usuario distinto del administrador intenta acceder.
+        /// * `Error::UsuarioNoExistente` si el miembro no es un usuario registrado en el sistema.
+        /// * `Error::UsuarioNoPermitido` si el miembro es un administrador o el contrato de
+        /// reportes.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        /// * `Error::VotacionEnCurso` si la Eleccion ya inició y venció su margen de gracia.
+        /// * `Error::VotacionFinalizada` si la Eleccion finalizó.
+        /// * `Error::RegistroCerrado` si el registro de la Eleccion ya cerró.
+        /// * `Error::UsuarioYaEsCandidato` o `Error::UsuarioYaEsVotante` si el miembro ya
+        /// esta registrado en la Eleccion con ese rol, o con el otro si la Eleccion no permite
+        /// que los candidatos voten.
+        #[ink(message)]
+        pub fn registrar_y_aprobar(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+        ) -> Result<(), Error> {
+            Self::registrar_y_aprobar_interno(self, id_eleccion, id_miembro, rol)
+        }
+
+        fn registrar_y_aprobar_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_miembro: AccountId,
+            rol: Rol,
+        ) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            if !self.usuarios.contains(id_miembro) {
+                return Err(Error::UsuarioNoExistente);
+            }
+            self.validar_miembro_aprobable(id_miembro)?;
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            let tiempo = self.env().block_timestamp();
+            eleccion.permite_aprobacion(tiempo)?;
+            eleccion.verificar_registro(&id_miembro, &rol)?;
+
+            eleccion.añadir_miembro(id_miembro, rol.clone(), tiempo)?;
+            eleccion.aprobar_miembro(&id_miembro, &rol)?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+
+            self.agregar_eleccion_usuario(id_miembro, id_eleccion);
+
+            self.env().emit_event(EstadoAprobacionCambiado {
+                id_eleccion,
+                miembro: id_miembro,
+                rol,
+                estado: EstadoAprobacion::Aprobado,
+            });
+            Ok(())
+        }
+
+        /// Recibe el id de una elección y retorna su estado actual.
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        /// Devuelve `Error::VotacionCancelada` si la votacion fue cancelada.
+        #[ink(message)]
+        pub fn consultar_estado(&self, id_eleccion: u32) -> Result<EstadoDeEleccion, Error> {
+            Self::consultar_estado_interno(self, id_eleccion)
+        }
+        
+        fn consultar_estado_interno(&self, id_eleccion: u32) -> Result<EstadoDeEleccion, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if eleccion.cancelada {
+                return Err(Error::VotacionCancelada);
+            }
+            Ok(eleccion.consultar_estado(self.env().block_timestamp()))
+        }
+
+        /// Retorna `true` si la votación de la elección `id_eleccion` se encuentra abierta en
+        /// este momento. Una elección cancelada nunca está abierta.
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        #[ink(message)]
+        pub fn puede_votar_ahora(&self, id_eleccion: u32) -> Result<bool, Error> {
+            Self::puede_votar_ahora_interno(self, id_eleccion)
+        }
+
+        fn puede_votar_ahora_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok(!eleccion.cancelada
+                && eleccion.consultar_estado(self.env().block_timestamp())
+                    == EstadoDeEleccion::EnCurso)
+        }
+
+        /// Retorna `true` si el invocante podrá votar en la elección `id_eleccion`: es un votante
+        /// aprobado que aún no votó, y la elección no fue cancelada ni finalizó.
+        /// Para distinguir un registro pendiente de aprobación puede usarse `mi_estado_en_eleccion`.
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        #[ink(message)]
+        pub fn soy_elegible(&self, id_eleccion: u32) -> Result<bool, Error> {
+            Self::soy_elegible_interno(self, id_eleccion)
+        }
+
+        fn soy_elegible_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            let caller = self.env().caller();
+            Ok(!eleccion.cancelada
+                && eleccion.consultar_estado(self.env().block_timestamp())
+                    != EstadoDeEleccion::Finalizada
+                && eleccion
+                    .votantes_aprobados
+                    .iter()
+                    .any(|v| v.id == caller && !v.ha_votado))
+        }
+
+        /// Retorna el estado del invocante en la elección `id_eleccion`, según si está
+        /// registrado como votante o candidato y si fue aprobado.
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        #[ink(message)]
+        pub fn mi_estado_en_eleccion(&self, id_eleccion: u32) -> Result<EstadoMiembro, Error> {
+            Self::mi_estado_en_eleccion_interno(self, id_eleccion)
+        }
+
+        fn mi_estado_en_eleccion_interno(&self, id_eleccion: u32) -> Result<EstadoMiembro, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok(eleccion.get_estado_miembro(&self.env().caller()))
+        }
+
+        /// Retorna los ids de las elecciones en las que se registró el invocante, en el orden
+        /// en que lo hizo, sin importar si fue aprobado.
+        #[ink(message)]
+        pub fn mis_elecciones(&self) -> Vec<u32> {
+            Self::mis_elecciones_interno(self)
+        }
+
+        fn mis_elecciones_interno(&self) -> Vec<u32> {
+            self.elecciones_usuario
+                .get(self.env().caller())
+                .unwrap_or_default()
+        }
+
+        /// Permite a un votante aprobado consultar si ya emitió su voto en la elección
+        /// de id `id_eleccion`, incluyendo los votos en blanco.
+        /// Devuelve `Error::VotanteNoExistente` si el invocante no es un votante aprobado.
+        #[ink(message)]
+        pub fn he_votado(&self, id_eleccion: u32) -> Result<bool, Error> {
+            Self::he_votado_interno(self, id_eleccion)
+        }
+
+        fn he_votado_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            let caller = self.env().caller();
+            eleccion
+                .votantes_aprobados
+                .iter()
+                .find(|v| v.id == caller)
+                .map(|v| v.ha_votado)
+                .ok_or(Error::VotanteNoExistente)
+        }
+
+        /// Recibe el id de una elección y retorna el puesto por el que se vota y su descripción,
+        /// junto a sus fechas de inicio, cierre y creación, en tiempo unix (milisegundos).
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        #[ink(message)]
+        pub fn get_info_eleccion(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<(String, String, u64, u64, u64), Error> {
+            Self::get_info_eleccion_interno(self, id_eleccion)
+        }
+
+        fn get_info_eleccion_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<(String, String, u64, u64, u64), Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok((
+                eleccion.get_puesto(),
+                eleccion.descripcion,
+                eleccion.inicio.get_tiempo_unix(),
+                eleccion.fin.get_tiempo_unix(),
+                eleccion.creada_en,
+            ))
+        }
+
+        /// Retorna la cantidad de elecciones creadas en el sistema, incluyendo las canceladas.
+        /// Los ids de las elecciones van desde 1 hasta dicha cantidad.
+        #[ink(message)]
+        pub fn get_cantidad_elecciones(&self) -> u32 {
+            Self::get_cantidad_elecciones_interno(self)
+        }
+
+        fn get_cantidad_elecciones_interno(&self) -> u32 {
+            self.elecciones.len()
+        }
+
+        /// Retorna el id de cada elección del sistema junto a su estado actual.
+        /// Las elecciones canceladas no se incluyen.
+        #[ink(message)]
+        pub fn listar_elecciones(&self) -> Vec<(u32, EstadoDeEleccion)> {
+            Self::listar_elecciones_interno(self)
+        }
+
+        fn listar_elecciones_interno(&self) -> Vec<(u32, EstadoDeEleccion)> {
+            let tiempo = self.env().block_timestamp();
+            (0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .filter(|e| !e.cancelada)
+                .map(|e| (e.id, e.consultar_estado(tiempo)))
+                .collect()
+        }
+
+        /// Retorna la duración, en milisegundos, de la elección de id `id_eleccion`
+        /// desde su inicio hasta su cierre.
+        #[ink(message)]
+        pub fn get_duracion_eleccion(&self, id_eleccion: u32) -> Result<u64, Error> {
+            Self::get_duracion_eleccion_interno(self, id_eleccion)
+        }
+
+        fn get_duracion_eleccion_interno(&self, id_eleccion: u32) -> Result<u64, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok(Fecha::duracion_ms(&eleccion.inicio, &eleccion.fin))
+        }
+
+        /// Retorna la cantidad de votos del candidato aprobado `id_candidato` en la elección de
+        /// id `id_eleccion`. Solo es posible una vez finalizada, de forma que los conteos
+        /// parciales no influyan en la votación.
+        /// Retorna `Error::CandidatoNoExistente` si no es un candidato aprobado de la elección.
+        #[ink(message)]
+        pub fn get_votos_candidato(
+            &self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+        ) -> Result<u32, Error> {
+            Self::get_votos_candidato_interno(self, id_eleccion, id_candidato)
+        }
+
+        fn get_votos_candidato_interno(
+            &self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+        ) -> Result<u32, Error> {
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if eleccion.cancelada {
+                return Err(Error::VotacionCancelada);
+            }
+            self.requiere_finalizada(&eleccion)?;
+            eleccion
+                .candidatos_aprobados
+                .iter()
+                .find(|c| c.get_account_id() == id_candidato)
+                .map(|c| c.get_votos())
+                .ok_or(Error::CandidatoNoExistente)
+        }
+
+        /// Permite al administrador asignar la elección `id_eleccion` a un `grupo`, o quitarla
+        /// del que pertenezca si es `None`, antes de que esta inicie. Las elecciones de un mismo
+        /// grupo pueden presentarse juntas como una boleta de varios puestos, aunque cada una
+        /// se vota de forma independiente.
+        /// Retorna error si:
+        /// * `Error::PermisosInsuficientes` si el invocante no es el administrador.
+        /// * `Error::VotacionNoExiste` si la Eleccion no existe.
+        /// * `Error::VotacionEnCurso` o `Error::VotacionFinalizada` si la Eleccion ya inició.
+        /// * `Error::VotacionCancelada` si la Eleccion fue cancelada.
+        #[ink(message)]
+        pub fn set_grupo(&mut self, id_eleccion: u32, grupo: Option<u32>) -> Result<(), Error> {
+            Self::set_grupo_interno(self, id_eleccion, grupo)
+        }
+
+        fn set_grupo_interno(&mut self, id_eleccion: u32, grupo: Option<u32>) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.set_grupo(grupo, self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
+
+        /// Retorna el id de cada elección que pertenece al grupo `grupo`, en orden de creación.
+        #[ink(message)]
+        pub fn elecciones_del_grupo(&self, grupo: u32) -> Vec<u32> {
+            Self::elecciones_del_grupo_interno(self, grupo)
+        }
+
+        fn elecciones_del_grupo_interno(&self, grupo: u32) -> Vec<u32> {
+            (0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .filter(|e| e.grupo == Some(grupo))
+                .map(|e| e.id)
+                .collect()
+        }
+
+        /// Retorna el id de cada elección cuyo puesto coincide exactamente con `puesto`,
+        /// distinguiendo mayúsculas de minúsculas.
+        #[ink(message)]
+        pub fn buscar_elecciones_por_puesto(&self, puesto: String) -> Vec<u32> {
+            Self::buscar_elecciones_por_puesto_interno(self, puesto)
+        }
+
+        fn buscar_elecciones_por_puesto_interno(&self, puesto: String) -> Vec<u32> {
+            (0..self.elecciones.len())
+                .filter_map(|i| self.elecciones.get(i))
+                .filter(|e| e.get_puesto() == puesto)
+                .map(|e| e.id)
+                .collect()
+        }
+
+        /// Retorna el tiempo actual del bloque.
+        /// Devuelve `Error::PermisosInsuficientes` si no es llamado por el admin.
+        #[ink(message)]
+        pub fn get_block_timestamp(&self) -> Result<u64, Error> {
+            Self::get_block_timestamp_interno(&self)
+        }
+
+        pub fn get_block_timestamp_interno(&self) -> Result<u64, Error> {
+            match self.es_admin() {
+                true => Ok(self.env().block_timestamp()),
+                false => Err(Error::PermisosInsuficientes),
+            }
+        }
+
+        /// Le permite a un registrado en el sistema votar por un candidato
+        /// `id_candidato` en una elección `id_eleccion`, solo si el usuario
+        /// invocante está aprobado en la misma.
+        /// `nonce` identifica la transacción: si el invocante ya votó con el mismo `nonce`, el
+        /// reenvío retorna `Ok(())` sin registrar un nuevo voto. Con otro `nonce` se devuelve
+        /// `Error::VotanteYaVoto`.
+        /// Devuelve `Error::UsuarioNoPermitido` si el invocante es el contrato de reportes.
+        /// Emite el evento `VotoEmitido` si el voto se registra.
+        #[ink(message)]
+        pub fn votar(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            nonce: u64,
+        ) -> Result<(), Error> {
+            Self::votar_interno(self, id_eleccion, id_candidato, nonce)
+        }
+        
+        fn votar_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_candidato: AccountId,
+            nonce: u64,
+        ) -> Result<(), Error> {
+            if self.es_contrato_reportes() {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            let voto_nuevo = eleccion.votar_con_nonce(
+                self.env().caller(),
+                id_candidato,
+                nonce,
+                self.env().block_timestamp(),
+            )?;
+            if !voto_nuevo {
+                return Ok(());
+            }
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Le permite a un votante que ya votó en una elección `id_eleccion` cambiar su voto
+        /// al candidato `nuevo_candidato`, mientras la elección se encuentre en curso.
+        /// Devuelve `Error::VotanteNoHaVotado` si el invocante aún no ha votado.
+        /// Devuelve `Error::UsuarioNoPermitido` si el invocante es el contrato de reportes.
+        #[ink(message)]
+        pub fn cambiar_voto(
+            &mut self,
+            id_eleccion: u32,
+            nuevo_candidato: AccountId,
+        ) -> Result<(), Error> {
+            Self::cambiar_voto_interno(self, id_eleccion, nuevo_candidato)
+        }
+
+        fn cambiar_voto_interno(
+            &mut self,
+            id_eleccion: u32,
+            nuevo_candidato: AccountId,
+        ) -> Result<(), Error> {
+            if self.es_contrato_reportes() {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.cambiar_voto(
+                self.env().caller(),
+                nuevo_candidato,
+                self.env().block_timestamp(),
+            )?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            Ok(())
+        }
+
+        /// Le permite a un registrado en el sistema emitir un voto en blanco en una
+        /// elección `id_eleccion`, solo si el usuario invocante está aprobado en la misma.
+        /// El voto cuenta para la participación pero no para ningún candidato.
+        /// Devuelve `Error::UsuarioNoPermitido` si el invocante es el contrato de reportes.
+        #[ink(message)]
+        pub fn votar_en_blanco(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            Self::votar_en_blanco_interno(self, id_eleccion)
+        }
+
+        fn votar_en_blanco_interno(&mut self, id_eleccion: u32) -> Result<(), Error> {
+            if self.es_contrato_reportes() {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.votar_en_blanco(self.env().caller(), self.env().block_timestamp())?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Le permite a un registrado en el sistema votar en la elección `id_eleccion` al usuario
+        /// `id_usuario` aunque no sea candidato, si la elección permite votos write-in.
+        /// Si `id_usuario` es un votante aprobado, se lo incorpora a los candidatos aprobados.
+        /// Devuelve `Error::WriteInNoPermitido` si la elección no lo permite.
+        /// Devuelve `Error::UsuarioNoPermitido` si el invocante es el contrato de reportes.
+        /// Emite el evento `VotoEmitido` si el voto se registra.
+        #[ink(message)]
+        pub fn votar_write_in(&mut self, id_eleccion: u32, id_usuario: AccountId) -> Result<(), Error> {
+            Self::votar_write_in_interno(self, id_eleccion, id_usuario)
+        }
+
+        fn votar_write_in_interno(
+            &mut self,
+            id_eleccion: u32,
+            id_usuario: AccountId,
+        ) -> Result<(), Error> {
+            if self.es_contrato_reportes() {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion.votar_write_in(
+                self.env().caller(),
+                id_usuario,
+                self.env().block_timestamp(),
+            )?;
+            self.elecciones.set(id_eleccion - 1, &eleccion);
+            self.env().emit_event(VotoEmitido {
+                id_eleccion,
+                votante: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Método interno que retorna `true` si el invocante del contrato es un administrador;
+        /// `false` en cualquier otro caso
+        fn es_admin(&self) -> bool {
+            self.admins.contains(self.env().caller())
+        }
+
+        /// Método interno que retorna `true` si el invocante del contrato es el
+        /// contrato de reportes
+        /// `false` en cualquier otro caso
+        fn es_contrato_reportes(&self) -> bool {
+            match self.contrato_reportes {
+                Some(c) => self.env().caller() == c,
+                None => false,
+            }
+        }
+
+        /// Agrega `id_eleccion` a las elecciones en las que se registró `id`, si aún no
+        /// se encuentra.
+        fn agregar_eleccion_usuario(&mut self, id: AccountId, id_eleccion: u32) {
+            let mut ids = self.elecciones_usuario.get(id).unwrap_or_default();
+            if !ids.contains(&id_eleccion) {
+                ids.push(id_eleccion);
+                self.elecciones_usuario.insert(id, &ids);
+            }
+        }
+
+        /// Quita `id_eleccion` de las elecciones en las que se registró `id`, una vez que
+        /// deja de ser miembro de la misma con todos sus roles.
+        fn quitar_eleccion_usuario(&mut self, id: AccountId, id_eleccion: u32) {
+            let sigue_siendo_miembro = self
+                .buscar_eleccion(id_eleccion)
+                .is_ok_and(|e| e.existe_usuario(&id).is_some());
+            if sigue_siendo_miembro {
+                return;
+            }
+            if let Some(mut ids) = self.elecciones_usuario.get(id) {
+                ids.retain(|&e| e != id_eleccion);
+                self.elecciones_usuario.insert(id, &ids);
+            }
+        }
+
+        /// Método interno que verifica que `id` pueda ser aprobado como miembro de una elección.
+        /// Retorna `Error::UsuarioNoPermitido` si es un administrador o el contrato de reportes,
+        /// cuentas que tampoco pueden registrarse por sí mismas.
+        fn validar_miembro_aprobable(&self, id: AccountId) -> Result<(), Error> {
+            if self.admins.contains(id) || self.contrato_reportes == Some(id) {
+                return Err(Error::UsuarioNoPermitido);
+            }
+            Ok(())
+        }
+
+        /// Método interno que retorna la elección de id `id_eleccion`.
+        /// Retorna `Error::VotacionNoExiste` si no se halla, incluso para el id 0
+        fn buscar_eleccion(&self, id_eleccion: u32) -> Result<Eleccion, Error> {
+            id_eleccion
+                .checked_sub(1)
+                .and_then(|i| self.elecciones.get(i))
+                .ok_or(Error::VotacionNoExiste)
+        }
+
+        /// Método interno que verifica que la elección haya finalizado, de forma que no se
+        /// expongan sus resultados mientras esté pendiente o en curso.
+        fn requiere_finalizada(&self, eleccion: &Eleccion) -> Result<(), Error> {
+            eleccion
+                .consultar_estado(self.env().block_timestamp())
+                .requiere_finalizada()
+        }
+
+        /// Retorna `Result<T, E>` con vector de ids e informacion del usuario.
+        /// Si la votacion no existe devuelve `Error::VotacionNoExiste`.
+        /// Si el invocante no es el admin devuelve `Error::PermisosInsuficientes`.
+        /// Si un votante de la elección no se encuentra registrado en el sistema
+        /// devuelve `Error::UsuarioNoExistente`.
+        #[ink(message)]
+        pub fn consultar_info_votantes_aprobados(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            Self::consultar_info_votantes_aprobados_interno(self, id_eleccion)
+        }
+        
+        fn consultar_info_votantes_aprobados_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion
+                .votantes_aprobados
+                .iter()
+                .map(|v| match self.usuarios.get(v.id) {
+                    Some(u) => Ok((v.id, u)),
+                    None => Err(Error::UsuarioNoExistente),
+                })
+                .collect()
+        }
+
+        /// Retorna una página de a lo sumo `limit` votantes aprobados de la elección
+        /// `id_eleccion`, comenzando desde la posición `offset`.
+        /// Si `offset` excede la cantidad de votantes aprobados se retorna un vector vacío.
+        /// Los errores son los mismos que los de `consultar_info_votantes_aprobados`.
+        #[ink(message)]
+        pub fn consultar_info_votantes_aprobados_pagina(
+            &self,
+            id_eleccion: u32,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            Self::consultar_info_votantes_aprobados_pagina_interno(self, id_eleccion, offset, limit)
+        }
+
+        fn consultar_info_votantes_aprobados_pagina_interno(
+            &self,
+            id_eleccion: u32,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(AccountId, Usuario)>, Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            eleccion
+                .votantes_aprobados
+                .iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|v| match self.usuarios.get(v.id) {
+                    Some(u) => Ok((v.id, u)),
+                    None => Err(Error::UsuarioNoExistente),
+                })
+                .collect()
+        }
+    }
+
+    // Reportes
+    impl SistemaVotacion {
+        /// Retorna `Result<T, E>` con vector de `Votante` o `Error` en caso
+        /// de que la votacion no exista o que quien le invoca no sea el
+        ///contrato de reportes
+        #[ink(message)]
+        pub fn get_votantes_aprobados(&self, id_eleccion: u32) -> Result<Vec<Votante>, Error> {
+            Self::get_votantes_aprobados_interno(self, id_eleccion)
+        }
+        
+        fn get_votantes_aprobados_interno(&self, id_eleccion: u32) -> Result<Vec<Votante>, Error> {
+            if self.es_contrato_reportes() {
+                let eleccion = self.buscar_eleccion(id_eleccion)?;
+                Ok(eleccion.votantes_aprobados)
+            } else {
+                Err(Error::PermisosInsuficientes)
+            }
+        }
+
+        // Obtener un usuario cuyo AccountId es `account_id`
+        // Devuelve `Err(Error::PermisosInsuficientes)` si el invocante no
+        // es el contrato de reportes
+        // Devuelve Error::UsuarioNoExistente si no existe el usuario
+        // de AccountId `account_id`
+        #[ink(message)]
+        pub fn get_usuarios(&self, account_id: AccountId) -> Result<Usuario, Error> {
+            Self::get_usuarios_interno(self, account_id)
+        }
+        
+        fn get_usuarios_interno(&self, account_id: AccountId) -> Result<Usuario, Error> {
+            if self.es_contrato_reportes() {
+                if let Some(id) = self.usuarios.get(account_id) {
+                    Ok(id)
+                } else {
+                    Err(Error::UsuarioNoExistente)
+                }
+            } else {
+                Err(Error::PermisosInsuficientes)
+            }
+        }
+
+        // Obtener el `AccountId` y la información de un usuario cuyo DNI es `dni`
+        // Devuelve `Err(Error::PermisosInsuficientes)` si el invocante no
+        // es el contrato de reportes
+        // Devuelve Error::UsuarioNoExistente si no existe un usuario con ese DNI
+        #[ink(message)]
+        pub fn get_usuario_por_dni(&self, dni: String) -> Result<(AccountId, Usuario), Error> {
+            Self::get_usuario_por_dni_interno(self, dni)
+        }
+
+        fn get_usuario_por_dni_interno(&self, dni: String) -> Result<(AccountId, Usuario), Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let Some(id) = self.id_usuarios.get(&dni) else {
+                return Err(Error::UsuarioNoExistente);
+            };
+            match self.usuarios.get(id) {
+                Some(usuario) => Ok((id, usuario)),
+                None => Err(Error::UsuarioNoExistente),
+            }
+        }
+
+        /// # Reportes
+        /// Retorna los candidatos aprobados en la elección de id `id_eleccion` asociados a su voto.
+        /// Utiliza el `AccountId` asociado a los candidatos en la elección para buscar los
+        /// usuarios registrados en el sistema.
+        /// Verifica el estado de la elección y si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_candidatos(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<Candidato>, Error> {
+            Self::get_candidatos_interno(self, id_eleccion)
+        }
+        
+        fn get_candidatos_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<Candidato>, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            self.requiere_finalizada(&eleccion)?;
+            Ok(eleccion.candidatos_aprobados)
+        }
+
+        /// # Reportes
+        /// Retorna el `AccountId` y los votos de los candidatos aprobados en la elección de id
+        /// `id_eleccion`. El resultado se almacena la primera vez que se consulta una vez
+        /// finalizada la elección, y las siguientes consultas retornan el mismo resultado.
+        /// Verifica el estado de la elección y si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_resultado_final(
+            &mut self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            Self::get_resultado_final_interno(self, id_eleccion)
+        }
+
+        fn get_resultado_final_interno(
+            &mut self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, u32)>, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let mut eleccion = self.buscar_eleccion(id_eleccion)?;
+            let calculado = eleccion.resultado_final.is_some();
+            let resultado = eleccion.get_resultado_final(self.env().block_timestamp())?;
+            if !calculado {
+                self.elecciones.set(id_eleccion - 1, &eleccion);
+            }
+            Ok(resultado)
+        }
+
+        /// # Reportes
+        /// Retorna el `AccountId` de los candidatos aprobados en la elección de id `id_eleccion`,
+        /// sin incluir sus votos.
+        /// Solo es posible si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_candidatos_aprobados_ids(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<AccountId>, Error> {
+            Self::get_candidatos_aprobados_ids_interno(self, id_eleccion)
+        }
+
+        fn get_candidatos_aprobados_ids_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<AccountId>, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            Ok(eleccion.get_candidatos_verificados())
+        }
+
+        /// # Reportes
+        /// Retorna, para la elección de id `id_eleccion`, los pares (votante, candidato)
+        /// de cada voto emitido a un candidato. Los votos en blanco no se incluyen.
+        /// Solo es posible una vez finalizada la elección y si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_boletas(&self, id_eleccion: u32) -> Result<Vec<(AccountId, AccountId)>, Error> {
+            Self::get_boletas_interno(self, id_eleccion)
+        }
+
+        fn get_boletas_interno(
+            &self,
+            id_eleccion: u32,
+        ) -> Result<Vec<(AccountId, AccountId)>, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            self.requiere_finalizada(&eleccion)?;
+            Ok(eleccion
+                .votantes_aprobados
+                .iter()
+                .filter_map(|v| v.voto_a.map(|c| (v.id, c)))
+                .collect())
+        }
+
+        /// # Reportes
+        /// Retorna `true` si la cantidad de votos emitidos en la elección de id `id_eleccion`
+        /// alcanza su quorum mínimo. Los votos en blanco cuentan para el quorum.
+        /// Verifica que la elección haya finalizado y si el invocante es el contrato de reportes
+        /// Retorna `Error::VotacionCancelada` si la elección fue cancelada.
+        #[ink(message)]
+        pub fn get_quorum_alcanzado(&self, id_eleccion: u32) -> Result<bool, Error> {
+            Self::get_quorum_alcanzado_interno(self, id_eleccion)
+        }
+
+        fn get_quorum_alcanzado_interno(&self, id_eleccion: u32) -> Result<bool, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            if eleccion.cancelada {
+                return Err(Error::VotacionCancelada);
+            }
+            self.requiere_finalizada(&eleccion)?;
+            Ok(eleccion.quorum_alcanzado())
+        }
+
+        /// # Reportes
+        /// Retorna la cantidad de escaños a cubrir en la elección de id `id_eleccion`.
+        /// Verifica que la elección haya finalizado y si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_escaños(&self, id_eleccion: u32) -> Result<u32, Error> {
+            Self::get_escaños_interno(self, id_eleccion)
+        }
+
+        fn get_escaños_interno(&self, id_eleccion: u32) -> Result<u32, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            self.requiere_finalizada(&eleccion)?;
+            Ok(eleccion.escaños)
+        }
+
+        /// # Reportes
+        /// Retorna la cantidad de votos en blanco emitidos en la elección de id `id_eleccion`.
+        /// Verifica que la elección haya finalizado y si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn get_votos_en_blanco(&self, id_eleccion: u32) -> Result<u32, Error> {
+            Self::get_votos_en_blanco_interno(self, id_eleccion)
+        }
+
+        fn get_votos_en_blanco_interno(&self, id_eleccion: u32) -> Result<u32, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let eleccion = self.buscar_eleccion(id_eleccion)?;
+            self.requiere_finalizada(&eleccion)?;
+            Ok(eleccion.votos_en_blanco)
+        }
+
+        /// # Reportes
+        /// Retorna `true` si el DNI del usuario `id` se encuentra asociado al mismo `id`,
+        /// de forma de detectar inconsistencias entre los usuarios y sus DNI.
+        /// Retorna `Error::UsuarioNoExistente` si el usuario no está registrado.
+        /// Verifica si el invocante es el contrato de reportes
+        #[ink(message)]
+        pub fn verificar_consistencia_usuario(&self, id: AccountId) -> Result<bool, Error> {
+            Self::verificar_consistencia_usuario_interno(self, id)
+        }
+
+        fn verificar_consistencia_usuario_interno(&self, id: AccountId) -> Result<bool, Error> {
+            if !self.es_contrato_reportes() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            let usuario = self.usuarios.get(id).ok_or(Error::UsuarioNoExistente)?;
+            Ok(self.id_usuarios.get(usuario.dni) == Some(id))
+        }
+
+        /// Permite al administrador establecer el AccountId del contrato que podrá acceder
+        /// a una serie de métodos que obtienen información de una elección
+        /// Retorna `Error::ContratoReportesInvalido` si el AccountId es de un administrador,
+        /// ya que de otra forma podría acceder directamente a la información reservada,
+        /// o si es el del propio contrato de votación.
+        #[ink(message)]
+        pub fn establecer_contrato_reportes(&mut self, account_id: AccountId) -> Result<(), Error> {
+            Self::establecer_contrato_reportes_interno(self, account_id)
+        }
+        
+        fn establecer_contrato_reportes_interno(&mut self, account_id: AccountId) -> Result<(), Error> {
+            if !self.es_admin() {
+                return Err(Error::PermisosInsuficientes);
+            }
+            if self.admins.contains(account_id) || account_id == self.env().account_id() {
+                return Err(Error::ContratoReportesInvalido);
+            }
+            self.contrato_reportes = Some(account_id);
+            Ok(()) //exitoso
+        }
+
+        /// Establece el contrato de reportes sin validaciones, de forma que las pruebas
+        /// puedan invocar como administrador los métodos reservados al contrato de reportes.
+        #[cfg(test)]
+        fn establecer_contrato_reportes_sin_validar(&mut self, account_id: AccountId) {
+            self.contrato_reportes = Some(account_id);
+        }
+
+        /// Retorna el AccountId del contrato de reportes establecido mediante
+        /// `establecer_contrato_reportes`, o `None` si aún no se estableció.
+        #[ink(message)]
+        pub fn get_contrato_reportes(&self) -> Option<AccountId> {
+            Self::get_contrato_reportes_interno(self)
+        }
+
+        fn get_contrato_reportes_interno(&self) -> Option<AccountId> {
+            self.contrato_reportes
+        }
+
+        /// Recibe el id de una votacion y retorna su estado actual.
+        /// Devuelve `Error::VotacionNoExiste` si la votacion no se halla.
+        /// Devuelve `Error::PermisosInsuficientes` si el invocanto no es
+        /// el contrato de reportes
+        #[ink(message)]
+        pub fn get_estado_eleccion(&self, id_votacion: u32) -> Result<EstadoDeEleccion, Error> {
+            Self::get_estado_eleccion_interno(self, id_votacion)
+        }
+
+        pub fn get_estado_eleccion_interno(&self, id_votacion: u32) -> Result<EstadoDeEleccion, Error> {
+            if self.es_contrato_reportes() {
+                self.consultar_estado(id_votacion)
+            } else {
+                Err(Error::PermisosInsuficientes)
+            }
+        }
+
+        /// Retorna el hash del código del contrato.
+        /// Utilizado por el contrato de reportes al instanciarse para constatar
+        /// que se comunica con el contrato de votación correcto.
+        #[ink(message)]
+        pub fn get_hash(&self) -> Hash {
+            self.env().own_code_hash().unwrap()
+        }
+
+        /// Retorna el `AccountId` del contrato.
+        #[ink(message)]
+        pub fn get_account_id(&self) -> AccountId {
+            self.env().account_id()
+        }
+    }
+
+    impl Default for SistemaVotacion {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ink::{
+            env::{DefaultEnvironment, Environment},
+            primitives::AccountId,
+        };
+
+        use crate::candidato::Candidato;
+
+        use super::*;
+
+        /* Helpers */
+
+        struct ContractEnv {
+            contract: SistemaVotacion,
+            contract_id: AccountId,
+            accounts: ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment>,
+        }
+
+        impl Default for ContractEnv {
+            fn default() -> Self {
+                Self {
+                    contract: SistemaVotacion::new_interno(),
+                    contract_id: ink::env::account_id::<ink::env::DefaultEnvironment>(),
+                    // Por defecto se superpone el `AccountId` del contrato
+                    // con el de alice, por eso esto es necesario.
+                    accounts: ink::env::test::DefaultAccounts {
+                        alice: <DefaultEnvironment as Environment>::AccountId::from([0xFF; 32]),
+                        bob: <DefaultEnvironment as Environment>::AccountId::from([0xFE; 32]),
+                        charlie: <DefaultEnvironment as Environment>::AccountId::from([0xFD; 32]),
+                        django: <DefaultEnvironment as Environment>::AccountId::from([0xFC; 32]),
+                        eve: <DefaultEnvironment as Environment>::AccountId::from([0xFB; 32]),
+                        frank: <DefaultEnvironment as Environment>::AccountId::from([0xFA; 32]),
+                    },
+                }
+            }
+        }
+
+        impl ContractEnv {
+            // Retorna un `ContractEnv` con 4 usuarios registrados en el sistema:
+            // Alice, Bob, Charlie y Django.
+            // (No está registrada eve, se utiliza para testear permisos)
+            // El administrador es de id `contract_id`, no se delegaron los privilegios.
+            fn new_inicializado() -> Self {
+                let mut env = ContractEnv::default();
+                ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+
+                // Registrar a Alice
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Alice"),
+                        String::from("A"),
+                        String::from("11111111"),
+                    )
+                    .unwrap();
+
+                // Registrar a Bob
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Bob"),
+                        String::from("B"),
+                        String::from("22222222"),
+                    )
+                    .unwrap();
+
+                // Registrar a Charlie
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Charlie"),
+                        String::from("C"),
+                        String::from("33333333"),
+                    )
+                    .unwrap();
+
+                // Registrar a Django
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Django"),
+                        String::from("D"),
+                        String::from("44444444"),
+                    )
+                    .unwrap();
+
+                env
+            }
+        }
+
+        /* Tests */
+
+        #[ink::test]
+        fn probar_registro_sistema() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Bob como invocante del contrato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+
+            // Registrar a bob en el sistema
+            assert!(env
+                .contract
+                .registrar_usuario_interno(
+                    String::from("Bob"),
+                    String::from(""),
+                    String::from("22222222")
+                )
+                .is_ok());
+
+            // El mismo AccountId intenta registrarse de nuevo, no debe poder
+            assert_eq!(
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Alice"),
+                        String::from(""),
+                        String::from("11111111")
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioExistente.to_string()
+            );
+
+            // Eve como invocante del contrato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            // El mismo dni intenta registrase de nuevo, no debe poder
+            assert_eq!(
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Eve"),
+                        String::from(""),
+                        String::from("22222222")
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioExistente.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_registro_sistema_dni_invalido() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+
+            // DNI vacío
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Bob"),
+                    String::from("B"),
+                    String::from("")
+                ),
+                Err(Error::DniInvalido)
+            );
+
+            // DNI no numérico
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Bob"),
+                    String::from("B"),
+                    String::from("22.222.222")
+                ),
+                Err(Error::DniInvalido)
+            );
+
+            // DNI demasiado corto
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Bob"),
+                    String::from("B"),
+                    String::from("222222")
+                ),
+                Err(Error::DniInvalido)
+            );
+            assert!(!env.contract.usuarios.contains(env.accounts.bob));
+
+            // DNI válido de 7 dígitos
+            assert!(env
+                .contract
+                .registrar_usuario_interno(
+                    String::from("Bob"),
+                    String::from("B"),
+                    String::from("2222222")
+                )
+                .is_ok());
+            assert!(env.contract.usuarios.contains(env.accounts.bob));
+            assert_eq!(
+                env.contract.id_usuarios.get(String::from("2222222")),
+                Some(env.accounts.bob)
+            );
+        }
+
+        #[ink::test]
+        fn probar_es_admin() {
+            let env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // La cuenta invocante es admin
+            assert!(env.contract.es_admin());
+
+            // Eve no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert!(!env.contract.es_admin());
+        }
+
+        #[ink::test]
+        fn probar_delegar_admin() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // La cuenta que crea el contrato le propone los privilegios a Alice
+            assert!(env.contract.delegar_admin_interno(env.accounts.alice).is_ok());
+
+            // Alice aún no es admin, la cuenta que crea el contrato lo sigue siendo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(!env.contract.es_admin());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env.contract.es_admin());
+
+            // Alice acepta los privilegios
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.aceptar_admin_interno().is_ok());
+            assert!(env.contract.es_admin());
+
+            // La cuenta que crea el contrato ya no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(!env.contract.es_admin());
+
+            // Alice le cede los privilegios a Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.delegar_admin_interno(env.accounts.bob).is_ok());
+
+            // Bob acepta y prueba si es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert!(env.contract.aceptar_admin_interno().is_ok());
+            assert!(env.contract.delegar_admin_interno(env.accounts.frank).is_ok());
+
+            // Alice ya no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(!env.contract.es_admin());
+
+            // Eve no puede delegar privilegios porque no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .delegar_admin_interno(env.accounts.alice)
+                    .unwrap_err()
+                    .to_string(),
+                Error::PermisosInsuficientes.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_admin() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // La cuenta que crea el contrato es el administrador
+            assert_eq!(env.contract.get_admin_interno(), env.contract_id);
+
+            // Mientras Alice no acepte los privilegios el administrador no cambia
+            env.contract.delegar_admin_interno(env.accounts.alice).unwrap();
+            assert_eq!(env.contract.get_admin_interno(), env.contract_id);
+
+            // Alice acepta y pasa a ser el administrador
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract.aceptar_admin_interno().unwrap();
+            assert_eq!(env.contract.get_admin_interno(), env.accounts.alice);
+            assert_eq!(env.contract.get_admins_interno(), vec![env.accounts.alice]);
+
+            // Cualquier cuenta puede consultarlo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(env.contract.get_admin_interno(), env.accounts.alice);
+        }
+
+        #[ink::test]
+        fn probar_aceptar_admin() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Sin una propuesta previa nadie puede aceptar los privilegios
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract.aceptar_admin_interno(),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // El admin propone a Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.delegar_admin_interno(env.accounts.bob).unwrap();
+
+            // Eve intenta aceptar los privilegios propuestos a Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.aceptar_admin_interno(),
+                Err(Error::PermisosInsuficientes)
+            );
+            assert!(!env.contract.es_admin());
+
+            // El admin se equivocó de cuenta y propone a Charlie en su lugar
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.delegar_admin_interno(env.accounts.charlie).unwrap();
+
+            // Bob ya no puede aceptar
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract.aceptar_admin_interno(),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Charlie acepta, el admin anterior pierde los privilegios
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert!(env.contract.aceptar_admin_interno().is_ok());
+            assert!(env.contract.es_admin());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(!env.contract.es_admin());
+
+            // La propuesta ya fue consumida, no puede aceptarse de nuevo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(
+                env.contract.aceptar_admin_interno(),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_new_con_admin() {
+            let env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // La cuenta que crea el contrato designa a Bob como administrador
+            let contrato = SistemaVotacion::new_con_admin_interno(env.accounts.bob);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert!(contrato.es_admin());
+
+            // Ni quien crea el contrato ni otra cuenta son administradores
+            for id in [env.contract_id, env.accounts.alice, env.accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                assert!(!contrato.es_admin());
+            }
+        }
+
+        #[ink::test]
+        fn probar_agregar_remover_admin() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // El único admin no puede removerse a sí mismo
+            assert_eq!(
+                env.contract.remover_admin_interno(env.contract_id),
+                Err(Error::UltimoAdmin)
+            );
+
+            // Alice es agregada como admin
+            assert!(env.contract.agregar_admin_interno(env.accounts.alice).is_ok());
+            assert_eq!(
+                env.contract.agregar_admin_interno(env.accounts.alice),
+                Err(Error::AdminExistente)
+            );
+
+            // Alice puede crear elecciones
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.es_admin());
+            assert!(env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .is_ok());
+
+            // Alice agrega a Bob y luego remueve a la cuenta que crea el contrato
+            assert!(env.contract.agregar_admin_interno(env.accounts.bob).is_ok());
+            assert_eq!(
+                env.contract.get_admins_interno(),
+                vec![env.contract_id, env.accounts.alice, env.accounts.bob]
+            );
+            assert!(env.contract.remover_admin_interno(env.contract_id).is_ok());
+            assert_eq!(
+                env.contract.get_admins_interno(),
+                vec![env.accounts.alice, env.accounts.bob]
+            );
+
+            // El administrador principal pasa a ser Alice, la admin restante más antigua
+            assert_eq!(env.contract.get_admin_interno(), env.accounts.alice);
+            assert_eq!(
+                env.contract.remover_admin_interno(env.contract_id),
+                Err(Error::AdminNoExistente)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(!env.contract.es_admin());
+            assert_eq!(
+                env.contract.agregar_admin_interno(env.accounts.eve),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Bob remueve a Alice, pero no puede removerse a sí mismo por ser el último
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert!(env.contract.remover_admin_interno(env.accounts.alice).is_ok());
+            assert_eq!(
+                env.contract.remover_admin_interno(env.accounts.bob),
+                Err(Error::UltimoAdmin)
+            );
+            assert_eq!(env.contract.get_admin_interno(), env.accounts.bob);
+            assert_eq!(env.contract.get_admins_interno(), vec![env.accounts.bob]);
+
+            // Eve no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.remover_admin_interno(env.accounts.bob),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_registro_sistema_admin() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Alice es ahora admin del sistema
+            assert!(env.contract.delegar_admin_interno(env.accounts.alice).is_ok());
+
+            // Alice como invocante del contrato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.aceptar_admin_interno().is_ok());
+            // Registrar a Alice en el sistema no debe ser posible
+            assert_eq!(
+                env.contract
+                    .registrar_usuario_interno(
+                        String::from("Alice"),
+                        String::from(""),
+                        String::from("11111111")
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioNoPermitido.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_crear_eleccion() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Se crea una elección con exito, de id = 1
+            assert_eq!(
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from("Presidente"),
+                        0,
+                        0,
+                        1,
+                        1,
+                        1970,
+                        1,
+                        1,
+                        1,
+                        1,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap(),
+                1
+            );
+            // Se crea una segunda elección con exito, de id = 2
+            assert_eq!(
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from("Presidente"),
+                        0,
+                        0,
+                        1,
+                        1,
+                        1970,
+                        1,
+                        1,
+                        1,
+                        1,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap(),
+                2
+            );
+
+            // La creación falla porque la fecha de finalización es posterior a la de inicio.
+            assert_eq!(
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from("Presidente"),
+                        1,
+                        1,
+                        1,
+                        1,
+                        1970,
+                        0,
+                        0,
+                        1,
+                        1,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::FechaFinalizacionInvalida.to_string()
+            );
+
+            // Bob como invocante del contrato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            // Bob no debe poder crear una elección, puesto que no es admin
+            assert_eq!(
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from("Presidente"),
+                        0,
+                        0,
+                        1,
+                        1,
+                        1970,
+                        1,
+                        0,
+                        1,
+                        1,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::PermisosInsuficientes.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_crear_eleccion_fechas_limite() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // La creación falla porque la fecha de inicio y de finalización coinciden,
+            // la elección nunca podría estar en curso
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    1,
+                    1,
+                    1,
+                    1970,
+                    1,
+                    1,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaFinalizacionInvalida)
+            );
+
+            // Se crea una elección que dura un único minuto
+            // inicio: 01/01/1970 01:01hs, fin: 01/01/1970 01:02hs
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    1,
+                    1,
+                    1,
+                    1970,
+                    1,
+                    2,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Primer instante de la elección
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(3660000);
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::EnCurso)
+            );
+
+            // Último milisegundo de la elección
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(3719999);
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::EnCurso)
+            );
+
+            // Primer instante tras finalizar
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(3720000);
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::Finalizada)
+            );
+        }
+
+        #[ink::test]
+        fn probar_crear_eleccion_puesto_invalido() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Puesto vacío
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from(""),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::PuestoInvalido)
+            );
+
+            // Puesto compuesto solo por espacios
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("   \t "),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::PuestoInvalido)
+            );
+            assert_eq!(env.contract.elecciones.len(), 0);
+
+            // Puesto válido
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Ok(1)
+            );
+
+            // Un usuario sin permisos recibe PermisosInsuficientes antes de validar el puesto
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from(""),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_crear_eleccion_fecha_invalida() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Mes de inicio inexistente
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    1,
+                    13,
+                    1970,
+                    0,
+                    0,
+                    1,
+                    1,
+                    1971,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // 31 de febrero como fecha de finalización
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    0,
+                    31,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // Hora y minuto de inicio fuera de rango
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    24,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    60,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // Día cero
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    0,
+                    1,
+                    1970,
+                    0,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // Hora y minuto de finalización fuera de rango
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    24,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    60,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // Año de inicio anterior a 1970
+            assert_eq!(
+                env.contract.crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    31,
+                    12,
+                    1969,
+                    0,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaInvalida)
+            );
+
+            // Ninguna elección fue creada
+            assert!(env.contract.listar_elecciones_interno().is_empty());
+        }
+
+        #[ink::test]
+        fn probar_validar_parametros_eleccion() {
+            let env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Cualquier cuenta puede validar los parámetros
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+
+            // Mes de inicio inexistente
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 1, 13, 1970, 0, 0, 1, 1, 1971),
+                Err(Error::FechaInvalida)
+            );
+
+            // 31 de febrero como fecha de finalización
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 1, 1, 1970, 0, 0, 31, 2, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // Hora y minuto de inicio fuera de rango
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(24, 0, 1, 1, 1970, 0, 0, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 60, 1, 1, 1970, 0, 0, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // Día cero
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 0, 1, 1970, 0, 0, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // Hora y minuto de finalización fuera de rango
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 1, 1, 1970, 24, 0, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 1, 1, 1970, 0, 60, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // Año de inicio anterior a 1970
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 31, 12, 1969, 0, 0, 2, 1, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // La fecha de inicio y de finalización coinciden
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(1, 1, 1, 1, 1970, 1, 1, 1, 1, 1970),
+                Err(Error::FechaFinalizacionInvalida)
+            );
+
+            // La fecha de finalización es anterior a la de inicio
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(0, 0, 2, 1, 1970, 0, 0, 1, 1, 1970),
+                Err(Error::FechaFinalizacionInvalida)
+            );
+
+            // Se aceptan parámetros válidos, con una elección que dura un único minuto
+            assert_eq!(
+                env.contract
+                    .validar_parametros_eleccion_interno(1, 1, 1, 1, 1970, 1, 2, 1, 1, 1970),
+                Ok(())
+            );
+
+            // Ninguna elección fue creada
+            assert_eq!(env.contract.get_cantidad_elecciones_interno(), 0);
+        }
+
+        #[ink::test]
+        fn probar_registro_eleccion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección en la que los candidatos no pueden votar
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    false,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Bob se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Charlie se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .is_ok());
+
+            // Django se confundió el id de la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(u32::MAX, Rol::Votante)
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionNoExiste.to_string()
+            );
+
+            // Eve se intenta registrar en la elección, pero no existe en el sistema
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioNoExistente.to_string()
+            );
+
+            // Alice olvidó que ya se había registrado, e intenta volver a hacerlo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioYaEsCandidato.to_string()
+            );
+
+            // Alice tampoco puede registrarse como votante, ya es candidata
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::UsuarioYaEsCandidato)
+            );
+
+            // Charlie olvidó que ya se había registrado, e intenta volver a hacerlo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                    .unwrap_err()
+                    .to_string(),
+                Error::UsuarioYaEsVotante.to_string()
+            );
+
+            // Charlie tampoco puede registrarse como candidato, ya es votante
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato),
+                Err(Error::UsuarioYaEsVotante)
+            );
+        }
+
+        #[ink::test]
+        fn probar_candidatos_pueden_votar() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // En la primera elección los candidatos pueden votar, en la segunda no
+            let mut ids = Vec::new();
+            for candidatos_pueden_votar in [true, false] {
+                ids.push(
+                    env.contract
+                        .crear_eleccion_interno(
+                            String::from("Presidente"),
+                            1,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            2,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            0,
+                            candidatos_pueden_votar,
+                        )
+                        .unwrap(),
+                );
+            }
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra como candidata y votante en la primera elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            for rol in [Rol::Candidato, Rol::Votante] {
+                env.contract
+                    .registrar_en_eleccion_interno(ids[0], rol)
+                    .unwrap();
+            }
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(ids[0], Rol::Votante),
+                Err(Error::UsuarioYaEsVotante)
+            );
+
+            // Al abandonar uno de sus roles la elección sigue entre las suyas
+            env.contract
+                .abandonar_eleccion_interno(ids[0], Rol::Votante)
+                .unwrap();
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[0]]);
+            env.contract
+                .registrar_en_eleccion_interno(ids[0], Rol::Votante)
+                .unwrap();
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[0]]);
+
+            // En la segunda elección solo puede registrarse con uno de los roles
+            env.contract
+                .registrar_en_eleccion_interno(ids[1], Rol::Candidato)
+                .unwrap();
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(ids[1], Rol::Votante),
+                Err(Error::UsuarioYaEsCandidato)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(ids[1], env.accounts.alice, Rol::Votante),
+                Err(Error::UsuarioYaEsCandidato)
+            );
+
+            // El administrador aprueba a Alice con ambos roles y a Bob como candidato
+            env.contract
+                .aprobar_miembros_batch_interno(
+                    ids[0],
+                    vec![(env.accounts.alice, Rol::Candidato), (env.accounts.alice, Rol::Votante)],
+                )
+                .unwrap();
+            env.contract
+                .registrar_y_aprobar_interno(ids[0], env.accounts.bob, Rol::Candidato)
+                .unwrap();
+            assert_eq!(env.contract.get_conteos_interno(ids[0]), Ok((0, 1, 0, 2)));
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Alice, candidata, puede votar en la primera elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract.votar_interno(ids[0], env.accounts.bob, 1).unwrap();
+            assert_eq!(env.contract.he_votado_interno(ids[0]), Ok(true));
+        }
+
+        #[ink::test]
+        fn probar_registro_eleccion_tiempo() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    // inicio: 31/1/1970 00:00hs
+                    0,
+                    0,
+                    31,
+                    1,
+                    1970,
+                    // fin: 1/2/1970 00:00hs
+                    0,
+                    0,
+                    1,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno previo al inicio
+            // 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra en la elección como `Rol::Candidato`.
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Establecer el tiempo al último instante antes del inicio de la eleccion.
+            // 31/01/1970 23:59hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2678340000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            // Ahora Charlie no puede registrarse
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionEnCurso.to_string()
+            );
+
+            // Establecer el tiempo del bloque al primer instante **inválido**, tras finalizar
+            // 01/02/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2678400000);
+
+            // Bob se olvidó de registrarse, y no puede realizarlo porque la elección finalizó
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionFinalizada.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_miembros_no_verificados() {
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Como la elección no tiene miembros, retorna un vector vacío
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
+                    .unwrap(),
+                vec![]
+            );
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
+                    .unwrap(),
+                vec![]
+            );
+
+            // Alice se registra como Candidato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Bob se registra como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // El único candidato no verificado es Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .0,
+                env.accounts.alice
+            );
+
+            // El único votante no verificado es Charlie
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .0,
+                env.accounts.charlie
+            );
+
+            // Eve no puede obtener los miembros de una elección no verificados
+            // porque no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
+                    .unwrap_err()
+                    .to_string(),
+                Error::PermisosInsuficientes.to_string()
+            );
+
+            // El admin intenta obtener una elección inexistente
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(u32::MAX, Rol::Votante)
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionNoExiste.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_miembros_no_verificados_pagina() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob se registran como candidatos, Charlie y Django como votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let info = |id: AccountId| (id, env.contract.usuarios.get(id).unwrap());
+
+            // Páginas de un candidato
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Candidato,
+                    0,
+                    1
+                ),
+                Ok(vec![info(env.accounts.alice)])
+            );
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Candidato,
+                    1,
+                    1
+                ),
+                Ok(vec![info(env.accounts.bob)])
+            );
+
+            // Una página que excede la cantidad de votantes los incluye a todos
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Votante,
+                    0,
+                    10
+                ),
+                Ok(vec![info(env.accounts.charlie), info(env.accounts.django)])
+            );
+
+            // La posición coincide con la cantidad de votantes
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Votante,
+                    2,
+                    10
+                ),
+                Ok(vec![])
+            );
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Votante,
+                    u32::MAX,
+                    u32::MAX
+                ),
+                Ok(vec![])
+            );
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.consultar_miembros_no_verificados_pagina_interno(
+                    eleccion_id,
+                    Rol::Votante,
+                    0,
+                    1
+                ),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_miembros_no_verificados_usuario_inexistente() {
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Se inserta directamente en la elección a Eve, que no está registrada en el sistema
+            let mut eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            eleccion
+                .votantes_pendientes
+                .push(Votante::new(env.accounts.eve));
+            env.contract.elecciones.set(eleccion_id - 1, &eleccion);
+
+            // El estado inconsistente se informa como error, sin producir panic
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante),
+                Err(Error::UsuarioNoExistente)
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_candidatos_disponibles() {
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Como la elección no tiene miembros, retorna un vector vacío
+            assert_eq!(
+                env.contract
+                    .consultar_candidatos_disponibles_interno(eleccion_id)
+                    .unwrap(),
+                vec![]
+            );
+
+            // Alice se registra como Candidato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Bob se registra como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Alice no se ha aprobado, por lo que continua vacia. El admin posee permisos para el método
+            assert_eq!(
+                env.contract
+                    .consultar_candidatos_disponibles_interno(eleccion_id)
+                    .unwrap(),
+                vec![]
+            );
+
+            // Se aprueba a Alice
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .is_ok());
+
+            // Se rechaza a Bob
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Votante,
+                    EstadoAprobacion::Rechazado,
+                )
+                .is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            // Se verifica que el AccountId de Alice se encuentre en los candidatos disponibles (verifica la propia Alice)
+            assert_eq!(
+                env.contract
+                    .consultar_candidatos_disponibles_interno(eleccion_id)
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .0,
+                env.accounts.alice
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            // Bob intenta ver los candidatos disponibles. No es posible porque fue rechazado de la elección
+            assert!(env
+                .contract
+                .consultar_candidatos_disponibles_interno(eleccion_id)
+                .is_err());
+        }
+
+        #[ink::test]
+        fn probar_estado_aprobacion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    2,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Bob se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Charlie se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .is_ok());
+
+            // Django se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .is_ok());
+
+            // Alice y Bob están pendientes de verificación como Candidatos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
+                    .unwrap()
+                    .iter()
+                    .map(|m| m.0)
+                    .collect::<Vec<_>>(),
+                vec![env.accounts.alice, env.accounts.bob]
+            );
+
+            // Charlie y Django están pendientes de verificación como Votantes
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
+                    .unwrap()
+                    .iter()
+                    .map(|m| m.0)
+                    .collect::<Vec<_>>(),
+                vec![env.accounts.charlie, env.accounts.django]
+            );
+
+            // Admin aprueba a Alice como Candidato
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .is_ok());
+
+            // Admin rechaza a Bob como Candidato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Candidato,
+                    EstadoAprobacion::Rechazado,
+                )
+                .is_ok());
+
+            // Admin aprueba a Charlie como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.charlie,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
+                .is_ok());
+
+            // Admin rechaza a Django como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Rechazado,
+                )
+                .is_ok());
+
+            // Admin no puede aprobar a Alice como Votante, ya que no está registrada en ese rol
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        env.accounts.alice,
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotanteNoExistente.to_string()
+            );
+
+            // Admin no puede aprobar a Frank porque no está registrado
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        env.accounts.frank,
+                        Rol::Candidato,
+                        EstadoAprobacion::Rechazado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::CandidatoNoExistente.to_string()
+            );
+
+            // Admin no puede aprobar a Frank porque se confundió el id de elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        u32::MAX,
+                        env.accounts.django,
+                        Rol::Votante,
+                        EstadoAprobacion::Rechazado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionNoExiste.to_string()
+            );
+
+            // Eve no puede cambiar el estado de aprobación, porque no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        env.accounts.alice,
+                        Rol::Candidato,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::PermisosInsuficientes.to_string(),
+            );
+
+            // Establecer el tiempo para que la eleccion este en curso
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(5400000);
+
+            // Admin no puede aprobar a Django porque la elección esta en curso
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        env.accounts.django,
+                        Rol::Votante,
+                        EstadoAprobacion::Rechazado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionEnCurso.to_string()
+            );
+
+            // Establecer el tiempo en uno posterior a la elección
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(1700000000);
+
+            // Admin no puede aprobar a Django porque la elección finalizó
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        env.accounts.django,
+                        Rol::Votante,
+                        EstadoAprobacion::Rechazado,
+                    )
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionFinalizada.to_string()
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_resultado_final() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob son candidatos, Charlie y Django votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, rol)
+                    .unwrap();
+            }
+
+            // Solo el contrato de reportes puede consultar el resultado
+            assert_eq!(
+                env.contract.get_resultado_final_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            for id in [env.accounts.charlie, env.accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .votar_interno(eleccion_id, env.accounts.alice, 1)
+                    .unwrap();
+            }
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.get_resultado_final_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            let resultado = vec![(env.accounts.alice, 2), (env.accounts.bob, 0)];
+            assert_eq!(
+                env.contract.get_resultado_final_interno(eleccion_id),
+                Ok(resultado.clone())
+            );
+
+            // El resultado quedó almacenado en la elección
+            let mut eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
+            assert_eq!(eleccion.resultado_final, Some(resultado.clone()));
+
+            // Aunque los candidatos se modifiquen, se retorna el mismo resultado
+            eleccion.candidatos_aprobados[1].votar().unwrap();
+            env.contract.elecciones.set(eleccion_id - 1, &eleccion);
+            assert_eq!(
+                env.contract.get_resultado_final_interno(eleccion_id),
+                Ok(resultado.clone())
+            );
+            assert_eq!(
+                env.contract.get_resultado_final_interno(eleccion_id),
+                Ok(resultado)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_candidatos() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Intento pedir los candidatos de una eleccion que no existe
+            assert_eq!(
+                env.contract
+                    .get_candidatos_interno(u32::MAX)
+                    .unwrap_err()
+                    .to_string(),
+                Error::VotacionNoExiste {}.to_string()
+            );
+
+            // Intento pedir los candidatos de una eleccion sin candidatos
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
+            assert!(env.contract.get_candidatos_interno(eleccion_id).unwrap().is_empty());
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Intento pedir los candidatos antes de que inicie la eleccion
+            assert_eq!(
+                env.contract.get_candidatos_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Alice se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Bob se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Charlie se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Intento pedir los candidatos mientras la eleccion esta en curso
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2770200000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.get_candidatos_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Intento pedir los candidatos de una eleccion sin candidatos aprobados
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
+            assert!(env.contract.get_candidatos_interno(eleccion_id).unwrap().is_empty());
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Admin aprueba a Alice como Candidato
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            // Admin rechaza a Bob como Candidato
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Candidato,
+                    EstadoAprobacion::Rechazado,
+                )
+                .unwrap();
+
+            // Admin aprueba a Charlie como Candidato
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.charlie,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            // Pido los candidatos aprobados
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
+            let candidatos = env.contract.get_candidatos_interno(eleccion_id).unwrap();
+            let alice = env.accounts.alice;
+            let charlie = env.accounts.charlie;
+            // Los candidatos deben ser Alice y Charlie ya que son los unicos aprobados
+            let mut response = vec![Candidato::new(alice), Candidato::new(charlie)];
+            assert_eq!(candidatos, response);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Django se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Admin aprueba a Django como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Django vota a Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            //assert!(
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+
+            // Ahora alice tiene un voto
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            let _ = response[0].votar();
+            assert_eq!(env.contract.get_candidatos_interno(eleccion_id).unwrap(), response);
+        }
+
+        #[ink::test]
+        fn probar_get_boletas() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // El contrato de reportes aún no fue establecido
+            assert_eq!(
+                env.contract.get_boletas_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            assert_eq!(
+                env.contract.get_boletas_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob se registran como Candidatos, Charlie y Django como Votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            assert_eq!(
+                env.contract.get_boletas_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Charlie vota a Bob y Django a Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract.votar_interno(eleccion_id, env.accounts.bob, 1).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.get_boletas_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(
+                env.contract.get_boletas_interno(eleccion_id).unwrap(),
+                vec![
+                    (env.accounts.charlie, env.accounts.bob),
+                    (env.accounts.django, env.accounts.alice),
+                ]
+            );
+
+            // Eve no es el contrato de reportes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_boletas_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_quorum_alcanzado() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear cuatro elecciones con quorum de 2, 3, 0 y 0 votos respectivamente
+            let mut ids = Vec::new();
+            for quorum in [2, 3, 0, 0] {
+                ids.push(
+                    env.contract
+                        .crear_eleccion_interno(
+                            String::from("Presidente"),
+                            1,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            2,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            quorum,
+                            true,
+                        )
+                        .unwrap(),
+                );
+            }
+
+            // La última elección se cancela antes de iniciar
+            env.contract.cancelar_eleccion_interno(ids[3]).unwrap();
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata, Charlie y Django votantes en las dos primeras elecciones
+            for id_eleccion in [ids[0], ids[1]] {
+                for (id, rol) in [
+                    (env.accounts.alice, Rol::Candidato),
+                    (env.accounts.charlie, Rol::Votante),
+                    (env.accounts.django, Rol::Votante),
+                ] {
+                    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                    env.contract
+                        .registrar_en_eleccion_interno(id_eleccion, rol.clone())
+                        .unwrap();
+                    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                    env.contract
+                        .cambiar_estado_aprobacion_interno(
+                            id_eleccion,
+                            id,
+                            rol,
+                            EstadoAprobacion::Aprobado,
+                        )
+                        .unwrap();
+                }
+            }
+
+            assert_eq!(
+                env.contract.get_quorum_alcanzado_interno(ids[0]),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Charlie y Django votan en ambas elecciones
+            for id_eleccion in [ids[0], ids[1]] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+                env.contract.votar_interno(id_eleccion, env.accounts.alice, 1).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+                env.contract.votar_en_blanco_interno(id_eleccion).unwrap();
+            }
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.get_quorum_alcanzado_interno(ids[0]),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+
+            // Dos votos alcanzan un quorum de 2, pero no uno de 3
+            assert_eq!(env.contract.get_quorum_alcanzado_interno(ids[0]), Ok(true));
+            assert_eq!(env.contract.get_quorum_alcanzado_interno(ids[1]), Ok(false));
+
+            // Sin quorum mínimo se alcanza aún sin votos
+            assert_eq!(env.contract.get_quorum_alcanzado_interno(ids[2]), Ok(true));
+
+            // Una elección cancelada no reporta quorum aunque haya pasado su fecha de cierre
+            assert_eq!(
+                env.contract.get_quorum_alcanzado_interno(ids[3]),
+                Err(Error::VotacionCancelada)
+            );
+
+            // Eve no es el contrato de reportes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_quorum_alcanzado_interno(ids[0]),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_escaños() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Concejales"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Establecer el tiempo del bloque en uno previo al inicio, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            assert_eq!(
+                env.contract.set_escaños_interno(eleccion_id, 0),
+                Err(Error::EscañosInvalidos)
+            );
+            env.contract.set_escaños_interno(eleccion_id, 3).unwrap();
+
+            // Los escaños solo se informan una vez finalizada la elección
+            assert_eq!(
+                env.contract.get_escaños_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Un usuario no puede establecer los escaños
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.set_escaños_interno(eleccion_id, 2),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+
+            // Alice no es el contrato de reportes
+            assert_eq!(
+                env.contract.get_escaños_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(env.contract.get_escaños_interno(eleccion_id), Ok(3));
+            assert_eq!(
+                env.contract.set_escaños_interno(eleccion_id, 2),
+                Err(Error::VotacionFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn probar_total_pendientes() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Sin elecciones no hay miembros pendientes
+            assert_eq!(env.contract.total_pendientes_interno(), Ok(0));
+
+            let mut ids = Vec::new();
+            for puesto in ["Presidente", "Gobernador"] {
+                ids.push(
+                    env.contract
+                        .crear_eleccion_interno(
+                            String::from(puesto),
+                            1,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            2,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            0,
+                            true,
+                        )
+                        .unwrap(),
+                );
+            }
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // En la primera elección se registran Alice como candidata y Bob como votante,
+            // en la segunda Charlie y Django como votantes
+            for (id_eleccion, id, rol) in [
+                (ids[0], env.accounts.alice, Rol::Candidato),
+                (ids[0], env.accounts.bob, Rol::Votante),
+                (ids[1], env.accounts.charlie, Rol::Votante),
+                (ids[1], env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(id_eleccion, rol)
+                    .unwrap();
+            }
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(env.contract.total_pendientes_interno(), Ok(4));
+
+            // Los miembros aprobados dejan de contarse
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    ids[1],
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+            assert_eq!(env.contract.total_pendientes_interno(), Ok(3));
+
+            // Los pendientes de una elección cancelada dejan de contarse
+            env.contract.cancelar_eleccion_interno(ids[1]).unwrap();
+            assert_eq!(env.contract.total_pendientes_interno(), Ok(2));
+
+            // Tampoco los de una elección finalizada
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(env.contract.total_pendientes_interno(), Ok(0));
+
+            // Un usuario no puede consultar el total
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.total_pendientes_interno(),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_estado() {
+            // inicializar sistema con usuarios registrados
+            let env = ContractEnv::new_inicializado();
+
+            // Probar consultar el estado de una eleccion que no existe
+            assert_eq!(
+                env.contract.consultar_estado_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Los otros casos de consultar_estado() ya fueron cubiertos en los tests anteriores
+        }
+
+        #[ink::test]
+        fn probar_finalizar_anticipadamente() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata aprobada, Charlie y Django votantes aprobados
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            // No es posible finalizar una elección que aún no inició
+            assert_eq!(
+                env.contract.finalizar_anticipadamente_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .votar_interno(eleccion_id, env.accounts.alice, 1)
+                .unwrap();
+
+            // Un usuario no puede finalizar la elección
+            assert_eq!(
+                env.contract.finalizar_anticipadamente_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .finalizar_anticipadamente_interno(eleccion_id)
+                .unwrap();
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::Finalizada)
+            );
+            assert_eq!(
+                env.contract.finalizar_anticipadamente_interno(eleccion_id),
+                Err(Error::VotacionFinalizada)
+            );
+
+            // Django ya no puede votar
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 1),
+                Err(Error::VotacionFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn probar_orden_candidatos() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice, Bob y Charlie son candidatos, Django votante
+            for id in [env.accounts.alice, env.accounts.bob, env.accounts.charlie] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, Rol::Candidato)
+                    .unwrap();
+            }
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.django, Rol::Votante)
+                .unwrap();
+
+            // Charlie encabeza la boleta, Alice y Bob empatan
+            for (id, orden) in [
+                (env.accounts.charlie, 1),
+                (env.accounts.alice, 2),
+                (env.accounts.bob, 2),
+            ] {
+                env.contract
+                    .set_orden_candidato_interno(eleccion_id, id, orden)
+                    .unwrap();
+            }
+            assert_eq!(
+                env.contract
+                    .set_orden_candidato_interno(eleccion_id, env.accounts.django, 0),
+                Err(Error::CandidatoNoExistente)
+            );
+
+            // Django consulta la boleta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.get_candidatos_ordenados_interno(eleccion_id),
+                Ok(vec![
+                    (env.accounts.charlie, 1),
+                    (env.accounts.alice, 2),
+                    (env.accounts.bob, 2),
+                ])
+            );
+
+            // Django no puede modificar el orden
+            assert_eq!(
+                env.contract
+                    .set_orden_candidato_interno(eleccion_id, env.accounts.alice, 0),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Eve no es miembro de la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_candidatos_ordenados_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .set_orden_candidato_interno(eleccion_id, env.accounts.alice, 0),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_perfil_candidato() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata aprobada y Django votante aprobado
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            // Alice establece su perfil
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .set_perfil_candidato_interno(
+                    eleccion_id,
+                    Some(String::from("Partido Azul")),
+                    Some(String::from("Más bibliotecas")),
+                )
+                .unwrap();
+
+            // Django no es candidato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.set_perfil_candidato_interno(eleccion_id, None, None),
+                Err(Error::CandidatoNoExistente)
+            );
+
+            // Django, votante aprobado, consulta los perfiles para su boleta
+            assert_eq!(
+                env.contract.get_perfiles_candidatos_interno(eleccion_id),
+                Ok(vec![(
+                    env.accounts.alice,
+                    Some(String::from("Partido Azul")),
+                    Some(String::from("Más bibliotecas"))
+                )])
+            );
+
+            // Charlie no es miembro de la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(
+                env.contract.get_perfiles_candidatos_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Con la elección en curso Alice ya no puede modificar su perfil
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.set_perfil_candidato_interno(eleccion_id, None, None),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_soy_elegible() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            assert_eq!(
+                env.contract.soy_elegible_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata aprobada, Bob y Charlie votantes aprobados y Django pendiente
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato)
+                .unwrap();
+            for id in [env.accounts.bob, env.accounts.charlie] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, Rol::Votante)
+                    .unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Antes del inicio solo Bob y Charlie son elegibles
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(false));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(false));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(false));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(true));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(true));
+
+            // En curso, deja de serlo una vez que vota
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(true));
+            env.contract
+                .votar_interno(eleccion_id, env.accounts.alice, 1)
+                .unwrap();
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(false));
+
+            // Una elección cancelada o finalizada no admite votantes elegibles
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            let cancelada_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Gobernador"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract
+                .registrar_y_aprobar_interno(cancelada_id, env.accounts.django, Rol::Votante)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(env.contract.soy_elegible_interno(cancelada_id), Ok(true));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.cancelar_eleccion_interno(cancelada_id).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(env.contract.soy_elegible_interno(cancelada_id), Ok(false));
+
+            // Bob no votó, pero la elección ya finalizó
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(true));
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(env.contract.soy_elegible_interno(eleccion_id), Ok(false));
+        }
+
+        #[ink::test]
+        fn probar_puede_votar_ahora() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Dos elecciones iguales, la segunda será cancelada
+            let mut ids = Vec::new();
+            for _ in 0..2 {
+                ids.push(
+                    env.contract
+                        .crear_eleccion_interno(
+                            String::from("Presidente"),
+                            1,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            2,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            0,
+                            true,
+                        )
+                        .unwrap(),
+                );
+            }
+
+            // Pendiente, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            env.contract.cancelar_eleccion_interno(ids[1]).unwrap();
+            assert_eq!(env.contract.puede_votar_ahora_interno(ids[0]), Ok(false));
+
+            // En curso, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(env.contract.puede_votar_ahora_interno(ids[0]), Ok(true));
+
+            // Cancelada, aunque el horario de votación esté en curso
+            assert_eq!(env.contract.puede_votar_ahora_interno(ids[1]), Ok(false));
+
+            // Finalizada
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(env.contract.puede_votar_ahora_interno(ids[0]), Ok(false));
+
+            assert_eq!(
+                env.contract.puede_votar_ahora_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_estado_id_cero() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            assert_eq!(eleccion_id, 1);
+
+            // El id 0 no corresponde a ninguna elección, aunque existan otras
+            assert_eq!(
+                env.contract.consultar_estado_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::Pendiente)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_info_eleccion() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Establecer el tiempo del bloque al momento de la creación, 01/01/1970 00:00:01hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(1000);
+
+            // Crear una elección: 01/01/1970 01:00hs - 02/01/1970 02:30hs
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    2,
+                    30,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Cualquier usuario puede consultar la información
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_info_eleccion_interno(eleccion_id),
+                Ok((String::from("Presidente"), String::new(), 3600000, 95400000, 1000))
+            );
+
+            // Solo el administrador establece la descripción
+            let descripcion = String::from("Elección del presidente del club");
+            assert_eq!(
+                env.contract
+                    .set_descripcion_interno(eleccion_id, descripcion.clone()),
+                Err(Error::PermisosInsuficientes)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .set_descripcion_interno(eleccion_id, descripcion.clone())
+                .unwrap();
+            assert_eq!(
+                env.contract.set_descripcion_interno(eleccion_id, "a".repeat(513)),
+                Err(Error::DescripcionDemasiadoLarga)
+            );
+            assert_eq!(
+                env.contract.get_info_eleccion_interno(eleccion_id),
+                Ok((String::from("Presidente"), descripcion, 3600000, 95400000, 1000))
+            );
+
+            // Una vez iniciada la elección no es posible modificarla
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(3600000);
+            assert_eq!(
+                env.contract.set_descripcion_interno(eleccion_id, String::new()),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Elección inexistente
+            assert_eq!(
+                env.contract.get_info_eleccion_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_cantidad_elecciones() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            assert_eq!(env.contract.get_cantidad_elecciones_interno(), 0);
+
+            for cantidad in 1..=3 {
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from("Presidente"),
+                        1,
+                        0,
+                        2,
+                        2,
+                        1970,
+                        2,
+                        0,
+                        2,
+                        2,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap();
+                assert_eq!(env.contract.get_cantidad_elecciones_interno(), cantidad);
+            }
+
+            // Una elección cancelada se sigue contando
+            env.contract.cancelar_eleccion_interno(1).unwrap();
+            assert_eq!(env.contract.get_cantidad_elecciones_interno(), 3);
+
+            // Cualquier usuario puede consultarla
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(env.contract.get_cantidad_elecciones_interno(), 3);
+        }
+
+        #[ink::test]
+        fn probar_listar_elecciones() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Sin elecciones, el listado es vacío
+            assert!(env.contract.listar_elecciones_interno().is_empty());
+
+            // Elección finalizada: 01/01/1970 00:00hs - 01/01/1970 01:00hs
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    0,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    1,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Elección en curso: 01/01/1970 01:00hs - 01/01/1970 03:00hs
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Gobernador"),
+                    1,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    3,
+                    0,
+                    1,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Elección pendiente: 02/01/1970 00:00hs - 03/01/1970 00:00hs
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Intendente"),
+                    0,
+                    0,
+                    2,
+                    1,
+                    1970,
+                    0,
+                    0,
+                    3,
+                    1,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en 01/01/1970 02:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(7200000);
+
+            // Cualquier usuario puede listar las elecciones
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.listar_elecciones_interno(),
+                vec![
+                    (1, EstadoDeEleccion::Finalizada),
+                    (2, EstadoDeEleccion::EnCurso),
+                    (3, EstadoDeEleccion::Pendiente),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn probar_validar_eleccion_lista() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Eve no puede validar la elección porque no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.validar_eleccion_lista_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.validar_eleccion_lista_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Sin candidatos aprobados
+            assert_eq!(
+                env.contract.validar_eleccion_lista_interno(eleccion_id),
+                Err(Error::CandidatosInsuficientes)
+            );
+
+            // Alice y Bob se registran como candidatos, de a uno son aprobados
+            for id in [env.accounts.alice, env.accounts.bob] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                    .unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            // Un único candidato aprobado, Bob aún está pendiente
+            assert_eq!(
+                env.contract.validar_eleccion_lista_interno(eleccion_id),
+                Err(Error::CandidatosInsuficientes)
+            );
+
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            // Con dos candidatos aprobados la elección está lista
+            assert!(env
+                .contract
+                .validar_eleccion_lista_interno(eleccion_id)
+                .is_ok());
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.validar_eleccion_lista_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_votos_emitidos() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata, Bob, Charlie y Django votantes
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato)
+                .unwrap();
+            for id in [env.accounts.bob, env.accounts.charlie, env.accounts.django] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, Rol::Votante)
+                    .unwrap();
+            }
+
+            // La elección aún no inició
+            assert_eq!(
+                env.contract.votos_emitidos_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(env.contract.votos_emitidos_interno(eleccion_id), Ok(0));
+
+            // Votan Bob y Charlie, Django aún no
+            for id in [env.accounts.bob, env.accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .votar_interno(eleccion_id, env.accounts.alice, 1)
+                    .unwrap();
+            }
+
+            // Un votante no puede consultar la participación
+            assert_eq!(
+                env.contract.votos_emitidos_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(env.contract.votos_emitidos_interno(eleccion_id), Ok(2));
+            assert_eq!(
+                env.contract.votos_emitidos_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_boleta() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob son candidatos aprobados, Charlie candidato pendiente y Django votante
+            for id in [env.accounts.alice, env.accounts.bob] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, Rol::Candidato)
+                    .unwrap();
+            }
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.django, Rol::Votante)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Bob ocupa el primer lugar de la boleta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .set_orden_candidato_interno(eleccion_id, env.accounts.alice, 2)
+                .unwrap();
+            env.contract
+                .set_orden_candidato_interno(eleccion_id, env.accounts.bob, 1)
+                .unwrap();
+
+            // La elección aún no inició
+            assert_eq!(
+                env.contract.get_boleta_interno(eleccion_id),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Se crea y cancela una segunda elección con las mismas fechas
+            let cancelada_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract
+                .cancelar_eleccion_interno(cancelada_id)
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.get_boleta_interno(cancelada_id),
+                Err(Error::VotacionCancelada)
+            );
+
+            // Cualquier cuenta puede consultar la boleta, sin el candidato pendiente
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            let boleta = vec![
+                (
+                    env.accounts.bob,
+                    env.contract.usuarios.get(env.accounts.bob).unwrap(),
+                ),
+                (
+                    env.accounts.alice,
+                    env.contract.usuarios.get(env.accounts.alice).unwrap(),
+                ),
+            ];
+            assert_eq!(env.contract.get_boleta_interno(eleccion_id), Ok(boleta));
+            assert_eq!(
+                env.contract.get_boleta_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(
+                env.contract.get_boleta_interno(eleccion_id),
+                Err(Error::VotacionFinalizada)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_conteos() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((0, 0, 0, 0)));
+
+            // Alice y Bob se registran como candidatos, Charlie y Django como votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+
+            // Se aprueba a Alice y a Django
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((1, 1, 1, 1)));
+
+            // Se rechaza a Charlie, deja de estar pendiente
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.charlie,
+                    Rol::Votante,
+                    EstadoAprobacion::Rechazado,
+                )
+                .unwrap();
+
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((0, 1, 1, 1)));
+
+            assert_eq!(
+                env.contract.get_conteos_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_conteos_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_registrar_y_aprobar() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    false,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Eve no es usuaria del sistema
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, env.accounts.eve, Rol::Votante),
+                Err(Error::UsuarioNoExistente)
+            );
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(u32::MAX, env.accounts.alice, Rol::Candidato),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Alice queda directamente aprobada como candidata y Charlie como votante
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato)
+                .unwrap();
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.charlie, Rol::Votante)
+                .unwrap();
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((0, 1, 0, 1)));
+
+            let eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
+            assert!(eleccion.existe_miembro_aprobado(&env.accounts.alice));
+            assert!(eleccion
+                .votantes_aprobados
+                .iter()
+                .any(|v| v.id == env.accounts.charlie));
+            assert_eq!(ink::env::test::recorded_events().count(), 2);
+
+            // Charlie ya se encuentra en la elección
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, env.accounts.charlie, Rol::Candidato),
+                Err(Error::UsuarioYaEsVotante)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, env.accounts.django, Rol::Votante),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_aprobar_miembros_batch() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra como candidata, Charlie y Django como votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+
+            let miembros = vec![
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Candidato),
+            ];
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .aprobar_miembros_batch_interno(eleccion_id, miembros.clone()),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .aprobar_miembros_batch_interno(u32::MAX, miembros.clone()),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Bob no se registró y Django no es candidato, el resto es aprobado
+            assert_eq!(
+                env.contract
+                    .aprobar_miembros_batch_interno(eleccion_id, miembros.clone()),
+                Ok(vec![
+                    (env.accounts.alice, Ok(())),
+                    (env.accounts.bob, Err(Error::CandidatoNoExistente)),
+                    (env.accounts.charlie, Ok(())),
+                    (env.accounts.django, Err(Error::CandidatoNoExistente)),
+                ])
+            );
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((1, 1, 0, 1)));
+
+            // Bob se registra como votante y luego pasa a ser el contrato de reportes,
+            // mientras que Django pasa a ser administrador
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.bob)
+                .unwrap();
+            env.contract.agregar_admin_interno(env.accounts.django).unwrap();
+
+            // Ninguno de los dos puede aprobarse, ni en lote ni individualmente
+            assert_eq!(
+                env.contract.aprobar_miembros_batch_interno(
+                    eleccion_id,
+                    vec![(env.accounts.bob, Rol::Votante), (env.accounts.django, Rol::Votante)]
+                ),
+                Ok(vec![
+                    (env.accounts.bob, Err(Error::UsuarioNoPermitido)),
+                    (env.accounts.django, Err(Error::UsuarioNoPermitido)),
+                ])
+            );
+            for id in [env.accounts.bob, env.accounts.django] {
+                assert_eq!(
+                    env.contract.cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    ),
+                    Err(Error::UsuarioNoPermitido)
+                );
+            }
+            assert_eq!(
+                env.contract.registrar_y_aprobar_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Candidato
+                ),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((2, 1, 0, 1)));
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.aprobar_miembros_batch_interno(
+                    eleccion_id,
+                    vec![(env.accounts.django, Rol::Votante)]
+                ),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_extender_fin() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Elección del 02/02/1970 01:00hs al 02/02/1970 02:00hs
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno anterior al inicio, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.extender_fin_interno(eleccion_id, 0, 0, 3, 2, 1970),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.extender_fin_interno(u32::MAX, 0, 0, 3, 2, 1970),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract.extender_fin_interno(eleccion_id, 0, 0, 30, 2, 1970),
+                Err(Error::FechaInvalida)
+            );
+
+            // El nuevo cierre es anterior al inicio
+            assert_eq!(
+                env.contract.extender_fin_interno(eleccion_id, 0, 0, 1, 2, 1970),
+                Err(Error::FechaFinalizacionInvalida)
+            );
+
+            // Se extiende el cierre al 03/02/1970 00:00hs
+            assert!(env
+                .contract
+                .extender_fin_interno(eleccion_id, 0, 0, 3, 2, 1970)
+                .is_ok());
+            let (_, _, _, fin, _) = env.contract.get_info_eleccion_interno(eleccion_id).unwrap();
+            assert_eq!(fin, Fecha::new(0, 0, 0, 3, 2, 1970).get_tiempo_unix());
+
+            // Con la elección en curso ya no es posible modificar su cierre
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.extender_fin_interno(eleccion_id, 0, 0, 4, 2, 1970),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_id_eleccion_cero() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Ninguna elección tiene id 0, no debe producirse un desbordamiento
+            assert_eq!(
+                env.contract.consultar_estado_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(0, Rol::Votante),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract.votar_interno(0, env.accounts.bob, 1),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Mensajes del administrador
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.cambiar_estado_aprobacion_interno(
+                    0,
+                    env.accounts.alice,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado
+                ),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract
+                    .consultar_miembros_no_verificados_interno(0, Rol::Votante),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract.consultar_info_votantes_aprobados_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Mensajes del contrato de reportes, con fines de pruebas igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+            assert_eq!(
+                env.contract.get_candidatos_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+            assert_eq!(
+                env.contract.get_votantes_aprobados_interno(0),
+                Err(Error::VotacionNoExiste)
+            );
+        }
+
+        #[ink::test]
+        fn probar_mi_estado_en_eleccion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob se registran como candidatos, Charlie y Django como votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+
+            // Se aprueba a Alice y a Django
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            for (id, estado) in [
+                (env.accounts.alice, EstadoMiembro::CandidatoAprobado),
+                (env.accounts.bob, EstadoMiembro::CandidatoPendiente),
+                (env.accounts.charlie, EstadoMiembro::VotantePendiente),
+                (env.accounts.django, EstadoMiembro::VotanteAprobado),
+                (env.accounts.eve, EstadoMiembro::NoRegistrado),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                assert_eq!(env.contract.mi_estado_en_eleccion_interno(eleccion_id), Ok(estado));
+            }
+
+            assert_eq!(
+                env.contract.mi_estado_en_eleccion_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+        }
+
+        #[ink::test]
+        fn probar_abandonar_eleccion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra por error como votante, Bob como candidato
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Alice indica un rol con el que no se registró
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract
+                    .abandonar_eleccion_interno(eleccion_id, Rol::Candidato),
+                Err(Error::CandidatoNoExistente)
+            );
+
+            // Alice abandona la elección y se registra como candidata
+            assert!(env
+                .contract
+                .abandonar_eleccion_interno(eleccion_id, Rol::Votante)
+                .is_ok());
+            assert_eq!(
+                env.contract.mi_estado_en_eleccion_interno(eleccion_id),
+                Ok(EstadoMiembro::NoRegistrado)
+            );
+            assert!(env
+                .contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .is_ok());
+
+            // Charlie no está registrado en la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(
+                env.contract.abandonar_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::VotanteNoExistente)
+            );
+            assert_eq!(
+                env.contract.abandonar_eleccion_interno(u32::MAX, Rol::Votante),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Bob ya no puede abandonar la elección en curso
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract
+                    .abandonar_eleccion_interno(eleccion_id, Rol::Candidato),
+                Err(Error::VotacionEnCurso)
+            );
+        }
+
+        #[ink::test]
+        fn probar_mis_elecciones() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let mut ids = Vec::new();
+            for puesto in ["Presidente", "Gobernador", "Intendente"] {
+                ids.push(
+                    env.contract
+                        .crear_eleccion_interno(
+                            String::from(puesto),
+                            1,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            2,
+                            0,
+                            2,
+                            2,
+                            1970,
+                            0,
+                            false,
+                        )
+                        .unwrap(),
+                );
+            }
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice aún no se registró en ninguna elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.mis_elecciones_interno().is_empty());
+
+            // Alice se registra en la tercera y la primera elección
+            env.contract
+                .registrar_en_eleccion_interno(ids[2], Rol::Votante)
+                .unwrap();
+            env.contract
+                .registrar_en_eleccion_interno(ids[0], Rol::Candidato)
+                .unwrap();
+
+            // Un registro fallido no se incluye
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(ids[0], Rol::Votante),
+                Err(Error::UsuarioYaEsCandidato)
+            );
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[2], ids[0]]);
+
+            // Bob solo ve sus propias elecciones
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(ids[1], Rol::Votante)
+                .unwrap();
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[1]]);
+
+            // Al abandonar una elección deja de incluirse
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .abandonar_eleccion_interno(ids[2], Rol::Votante)
+                .unwrap();
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[0]]);
+
+            // Tampoco se incluye si el administrador la rechaza
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    ids[0],
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Rechazado,
+                )
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.mis_elecciones_interno().is_empty());
+
+            // Al volver a registrarse se incluye una única vez
+            env.contract
+                .registrar_en_eleccion_interno(ids[0], Rol::Candidato)
+                .unwrap();
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[0]]);
+
+            // Ni si el administrador revoca su aprobación
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    ids[0],
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+            env.contract
+                .revocar_aprobacion_interno(ids[0], env.accounts.alice, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert!(env.contract.mis_elecciones_interno().is_empty());
+
+            // Bob no puede migrarse mientras sea miembro de una elección, por lo que su
+            // lista permanece en su cuenta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.bob, env.accounts.eve),
+                Err(Error::UsuarioMiembroDeEleccion)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![ids[1]]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert!(env.contract.mis_elecciones_interno().is_empty());
+        }
+
+        #[ink::test]
+        fn probar_resetear_aprobaciones() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob se registran como candidatos, Charlie y Django como votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+
+            // Se aprueba a Alice, Bob y Django
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .aprobar_miembros_batch_interno(
+                    eleccion_id,
+                    vec![
+                        (env.accounts.alice, Rol::Candidato),
+                        (env.accounts.bob, Rol::Candidato),
+                        (env.accounts.django, Rol::Votante),
+                    ],
+                )
+                .unwrap();
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((2, 1, 0, 1)));
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.resetear_aprobaciones_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Todos los miembros vuelven a estar pendientes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
+                .resetear_aprobaciones_interno(eleccion_id)
+                .is_ok());
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((2, 0, 2, 0)));
+
+            // Pueden ser aprobados nuevamente
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .is_ok());
+
+            assert_eq!(
+                env.contract.resetear_aprobaciones_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.resetear_aprobaciones_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((2, 0, 1, 1)));
+        }
+
+        #[ink::test]
+        fn probar_votos_con_peso() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Elección con quorum de 2 votantes
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    true,
+                )
+                .unwrap();
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob son candidatos, Charlie y Django votantes
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+
+            // El voto de Charlie, aún pendiente, pesa 5
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
+                .set_peso_votante_interno(eleccion_id, env.accounts.charlie, 5)
+                .is_ok());
+            assert_eq!(
+                env.contract
+                    .set_peso_votante_interno(eleccion_id, env.accounts.alice, 5),
+                Err(Error::VotanteNoExistente)
+            );
+
+            env.contract
+                .aprobar_miembros_batch_interno(
+                    eleccion_id,
+                    vec![
+                        (env.accounts.alice, Rol::Candidato),
+                        (env.accounts.bob, Rol::Candidato),
+                        (env.accounts.charlie, Rol::Votante),
+                        (env.accounts.django, Rol::Votante),
+                    ],
+                )
+                .unwrap();
+
+            // Eve no es administradora
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract
+                    .set_peso_votante_interno(eleccion_id, env.accounts.django, 2),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Ya no es posible modificar los pesos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract
+                    .set_peso_votante_interno(eleccion_id, env.accounts.django, 2),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Charlie vota a Alice y Django a Bob, luego Charlie cambia su voto a Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.bob, 1).unwrap();
+
+            let eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 5);
+            assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .cambiar_voto_interno(eleccion_id, env.accounts.bob)
+                .unwrap();
+
+            let eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 0);
+            assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 6);
+
+            // La cantidad de votos emitidos sigue siendo la cantidad de votantes
+            assert_eq!(eleccion.get_cantidad_votos(), 2);
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(env.contract.get_quorum_alcanzado_interno(eleccion_id), Ok(true));
+            assert!(env
+                .contract
+                .get_votantes_aprobados_interno(eleccion_id)
+                .unwrap()
+                .iter()
+                .all(|v| v.get_votos() == 1));
+        }
+
+        #[ink::test]
+        fn probar_get_candidatos_aprobados_ids() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Sin contrato de reportes establecido no es posible consultar
+            assert_eq!(
+                env.contract.get_candidatos_aprobados_ids_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            assert_eq!(
+                env.contract.get_candidatos_aprobados_ids_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice, Bob y Charlie se registran como candidatos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Admin aprueba solo a Alice y Charlie
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.charlie,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
+
+            assert_eq!(
+                env.contract.get_candidatos_aprobados_ids_interno(eleccion_id),
+                Ok(vec![env.accounts.alice, env.accounts.charlie])
+            );
+        }
+
+        #[ink::test]
+        fn probar_usuario_dni_existe() {
+            let env = ContractEnv::new_inicializado();
+
+            // Usuarios registrados
+            assert!(env.contract.usuario_existe_interno(env.accounts.alice));
+            assert!(env.contract.usuario_existe_interno(env.accounts.django));
+            assert!(env.contract.dni_existe_interno(String::from("11111111")));
+            assert!(env.contract.dni_existe_interno(String::from("44444444")));
+
+            // Usuarios no registrados
+            assert!(!env.contract.usuario_existe_interno(env.accounts.eve));
+            assert!(!env.contract.usuario_existe_interno(env.contract_id));
+            assert!(!env.contract.dni_existe_interno(String::from("55555555")));
+            assert!(!env.contract.dni_existe_interno(String::new()));
+        }
+
+        #[ink::test]
+        fn probar_dni_es_valido() {
+            let env = ContractEnv::default();
+
+            // Entre 7 y 8 dígitos numéricos
+            assert!(env.contract.dni_es_valido_interno(String::from("1234567")));
+            assert!(env.contract.dni_es_valido_interno(String::from("12345678")));
+            assert!(env.contract.dni_es_valido_interno(String::from("00000000")));
+
+            // Longitud incorrecta
+            assert!(!env.contract.dni_es_valido_interno(String::new()));
+            assert!(!env.contract.dni_es_valido_interno(String::from("123456")));
+            assert!(!env.contract.dni_es_valido_interno(String::from("123456789")));
+
+            // Caracteres no numéricos
+            assert!(!env.contract.dni_es_valido_interno(String::from("12.345.678")));
+            assert!(!env.contract.dni_es_valido_interno(String::from("1234567a")));
+            assert!(!env.contract.dni_es_valido_interno(String::from(" 1234567")));
+            assert!(!env.contract.dni_es_valido_interno(String::from("-1234567")));
+
+            // No registra ningún usuario
+            assert_eq!(env.contract.get_total_usuarios_interno(), 0);
+        }
+
+        #[ink::test]
+        fn probar_get_total_usuarios() {
+            let env = ContractEnv::default();
+            assert_eq!(env.contract.get_total_usuarios_interno(), 0);
+
+            // Se registran Alice, Bob, Charlie y Django
+            let mut env = ContractEnv::new_inicializado();
+            assert_eq!(env.contract.get_total_usuarios_interno(), 4);
+
+            // Alice intenta registrarse nuevamente
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Alice"),
+                    String::from("A"),
+                    String::from("11111111"),
+                ),
+                Err(Error::UsuarioExistente)
+            );
+
+            // Eve intenta registrarse con un DNI inválido
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("123"),
+                ),
+                Err(Error::DniInvalido)
+            );
+
+            // El administrador no puede registrarse
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Admin"),
+                    String::from("A"),
+                    String::from("99999999"),
+                ),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(env.contract.get_total_usuarios_interno(), 4);
+
+            // Eve se registra correctamente
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            env.contract
+                .registrar_usuario_interno(
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("55555555"),
+                )
+                .unwrap();
+            assert_eq!(env.contract.get_total_usuarios_interno(), 5);
+        }
+
+        #[ink::test]
+        fn probar_buscar_elecciones_por_puesto() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            assert!(env
+                .contract
+                .buscar_elecciones_por_puesto_interno(String::from("Presidente"))
+                .is_empty());
+
+            // Se crean las elecciones 1 a 4
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Gobernador"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract
+                .crear_eleccion_interno(
+                    String::from("presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            assert_eq!(
+                env.contract.buscar_elecciones_por_puesto_interno(String::from("Presidente")),
+                vec![1, 3]
+            );
+            assert_eq!(
+                env.contract.buscar_elecciones_por_puesto_interno(String::from("Gobernador")),
+                vec![2]
+            );
+
+            // La búsqueda distingue mayúsculas de minúsculas y requiere coincidencia exacta
+            assert_eq!(
+                env.contract.buscar_elecciones_por_puesto_interno(String::from("presidente")),
+                vec![4]
+            );
+            assert!(env
+                .contract
+                .buscar_elecciones_por_puesto_interno(String::from("Presi"))
+                .is_empty());
+        }
+
+        #[ink::test]
+        fn probar_elecciones_del_grupo() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Se crean las elecciones 1 a 4, para distintos puestos
+            for puesto in ["Presidente", "Gobernador", "Intendente", "Concejal"] {
+                env.contract
+                    .crear_eleccion_interno(
+                        String::from(puesto),
+                        1,
+                        0,
+                        2,
+                        2,
+                        1970,
+                        2,
+                        0,
+                        2,
+                        2,
+                        1970,
+                        0,
+                        true,
+                    )
+                    .unwrap();
+            }
+            assert!(env.contract.elecciones_del_grupo_interno(1).is_empty());
+
+            // Un usuario no puede agrupar elecciones
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract.set_grupo_interno(1, Some(1)),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Las elecciones 1, 2 y 4 forman el grupo 1, y la 3 el grupo 2
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            for id in [4, 1, 2] {
+                env.contract.set_grupo_interno(id, Some(1)).unwrap();
+            }
+            env.contract.set_grupo_interno(3, Some(2)).unwrap();
+            assert_eq!(env.contract.elecciones_del_grupo_interno(1), vec![1, 2, 4]);
+            assert_eq!(env.contract.elecciones_del_grupo_interno(2), vec![3]);
+            assert!(env.contract.elecciones_del_grupo_interno(3).is_empty());
+
+            // La elección 4 se quita del grupo
+            env.contract.set_grupo_interno(4, None).unwrap();
+            assert_eq!(env.contract.elecciones_del_grupo_interno(1), vec![1, 2]);
+            assert_eq!(
+                env.contract.set_grupo_interno(5, Some(1)),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.set_grupo_interno(4, Some(1)),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Cualquier cuenta puede consultar las elecciones de un grupo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(env.contract.elecciones_del_grupo_interno(1), vec![1, 2]);
+        }
+
+        #[ink::test]
+        fn probar_crear_segunda_vuelta() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Eve se registra en el sistema
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            env.contract
+                .registrar_usuario_interno(
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("55555555"),
+                )
+                .unwrap();
+
+            // Crea la segunda vuelta de `id_origen` para el 01/01/1971 de 01:00hs a 02:00hs
+            let crear_segunda_vuelta = |contrato: &mut SistemaVotacion, id_origen: u32| {
+                contrato.crear_segunda_vuelta_interno(id_origen, 1, 0, 1, 1, 1971, 2, 0, 1, 1, 1971)
+            };
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Elección sin candidatos
+            let eleccion_vacia_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            assert_eq!(
+                crear_segunda_vuelta(&mut env.contract, u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Bob se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Charlie se registra en la elección como `Rol::Candidato`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Django se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Eve se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Admin aprueba a todos los miembros, el voto de Django vale doble
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.charlie, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+                (env.accounts.eve, Rol::Votante),
+            ] {
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+            env.contract
+                .set_peso_votante_interno(eleccion_id, env.accounts.django, 2)
+                .unwrap();
+
+            // Configuración de la elección de origen
+            env.contract
+                .set_descripcion_interno(eleccion_id, String::from("Elección nacional"))
+                .unwrap();
+            env.contract.set_gracia_registro_interno(eleccion_id, 60000).unwrap();
+            env.contract.set_escaños_interno(eleccion_id, 2).unwrap();
+            env.contract.set_permite_write_in_interno(eleccion_id, true).unwrap();
+            env.contract
+                .invitar_interno(eleccion_id, vec![env.accounts.django, env.accounts.eve])
+                .unwrap();
+            env.contract.set_modo_invitacion_interno(eleccion_id, true).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .set_perfil_candidato_interno(
+                    eleccion_id,
+                    Some(String::from("Partido B")),
+                    Some(String::from("Propuesta B")),
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Django vota a Bob y Eve a Charlie, Alice no recibe votos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.bob, 1).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            env.contract.votar_interno(eleccion_id, env.accounts.charlie, 1).unwrap();
+
+            // La elección de origen aún está en curso
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                crear_segunda_vuelta(&mut env.contract, eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+
+            // Un usuario no puede crear la segunda vuelta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                crear_segunda_vuelta(&mut env.contract, eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Una elección sin candidatos no puede tener segunda vuelta
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                crear_segunda_vuelta(&mut env.contract, eleccion_vacia_id),
+                Err(Error::CandidatosInsuficientes)
+            );
+
+            let segunda_vuelta_id = crear_segunda_vuelta(&mut env.contract, eleccion_id).unwrap();
+            assert_eq!(segunda_vuelta_id, 3);
+
+            // La segunda vuelta solo contiene a Bob y Charlie, sin votos ni votantes
+            let segunda_vuelta = env.contract.buscar_eleccion(segunda_vuelta_id).unwrap();
+            assert_eq!(segunda_vuelta.get_puesto(), String::from("Presidente"));
+            assert_eq!(
+                segunda_vuelta.get_candidatos_verificados(),
+                vec![env.accounts.bob, env.accounts.charlie]
+            );
+            assert!(segunda_vuelta.candidatos_aprobados.iter().all(|c| c.get_votos() == 0));
+            assert_eq!(segunda_vuelta.get_conteos(), (0, 0, 0, 2));
+
+            // Conserva la configuración de la original, pero cubre un único escaño sin write-in
+            assert_eq!(segunda_vuelta.descripcion, String::from("Elección nacional"));
+            assert_eq!(segunda_vuelta.gracia_ms, 60000);
+            assert!(segunda_vuelta.modo_invitacion);
+            assert_eq!(
+                segunda_vuelta.invitados,
+                vec![env.accounts.django, env.accounts.eve]
+            );
+            assert_eq!(segunda_vuelta.escaños, 1);
+            assert!(!segunda_vuelta.permite_write_in);
+            assert_eq!(segunda_vuelta.grupo, None);
+
+            // Bob conserva su partido y propuesta
+            let bob = &segunda_vuelta.candidatos_aprobados[0];
+            assert_eq!(bob.partido, Some(String::from("Partido B")));
+            assert_eq!(bob.propuesta, Some(String::from("Propuesta B")));
+
+            // La segunda vuelta figura entre las elecciones de los finalistas
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract.mis_elecciones_interno(),
+                vec![eleccion_id, segunda_vuelta_id]
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(env.contract.mis_elecciones_interno(), vec![eleccion_id]);
+        }
+
+        #[ink::test]
+        fn probar_get_votos_candidato() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(u32::MAX, env.accounts.alice),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob se registran como candidatos, Django como votante
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.alice),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Django vota a Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+
+            // Los votos no se conocen mientras la elección está en curso
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.alice),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.alice),
+                Ok(1)
+            );
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.bob),
+                Ok(0)
+            );
+
+            // Ni un votante ni un usuario ajeno a la elección son candidatos
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.django),
+                Err(Error::CandidatoNoExistente)
+            );
+            assert_eq!(
+                env.contract.get_votos_candidato_interno(eleccion_id, env.accounts.charlie),
+                Err(Error::CandidatoNoExistente)
+            );
+        }
+
+        #[ink::test]
+        fn probar_requiere_finalizada() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            let eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
+
+            // Pendiente, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            assert_eq!(
+                env.contract.requiere_finalizada(&eleccion),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // En curso, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.requiere_finalizada(&eleccion),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Finalizada
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(env.contract.requiere_finalizada(&eleccion), Ok(()));
+        }
+
+        #[ink::test]
+        fn probar_migrar_usuario() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Un usuario no puede migrar registros
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.alice, env.accounts.eve),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // El usuario de origen no existe
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.eve, env.accounts.frank),
+                Err(Error::UsuarioNoExistente)
+            );
+
+            // La cuenta de destino ya pertenece a Bob
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.alice, env.accounts.bob),
+                Err(Error::UsuarioExistente)
+            );
+
+            // La cuenta de destino es del administrador
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.alice, env.contract_id),
+                Err(Error::UsuarioNoPermitido)
+            );
+
+            // La cuenta de destino es el contrato de reportes
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.frank)
+                .unwrap();
+            assert_eq!(
+                env.contract.migrar_usuario_interno(env.accounts.alice, env.accounts.frank),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert!(env.contract.usuario_existe_interno(env.accounts.alice));
+
+            // Alice migra su registro a la cuenta de Eve
+            env.contract
+                .migrar_usuario_interno(env.accounts.alice, env.accounts.eve)
+                .unwrap();
+
+            assert!(!env.contract.usuario_existe_interno(env.accounts.alice));
+            let usuario = env.contract.usuarios.get(env.accounts.eve).unwrap();
+            assert_eq!(usuario.nombre, String::from("Alice"));
+            assert_eq!(
+                env.contract.id_usuarios.get(String::from("11111111")),
+                Some(env.accounts.eve)
+            );
+            assert_eq!(env.contract.get_total_usuarios_interno(), 4);
+        }
+
+        #[ink::test]
+        fn probar_migrar_miembro_de_eleccion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es votante aprobada y Bob queda pendiente de aprobación
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Votante)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+
+            // Ninguno puede migrar su registro, aún finalizada la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            for viejo in [env.accounts.alice, env.accounts.bob] {
+                assert_eq!(
+                    env.contract.migrar_usuario_interno(viejo, env.accounts.eve),
+                    Err(Error::UsuarioMiembroDeEleccion)
+                );
+                assert!(env.contract.usuario_existe_interno(viejo));
+            }
+            assert!(!env.contract.usuario_existe_interno(env.accounts.eve));
+        }
+
+        #[ink::test]
+        fn probar_eliminar_usuario() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato)
+                .unwrap();
+
+            // Un usuario no puede eliminar registros
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            assert_eq!(
+                env.contract.eliminar_usuario_interno(env.accounts.charlie),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.eliminar_usuario_interno(env.accounts.eve),
+                Err(Error::UsuarioNoExistente)
+            );
+
+            // Charlie queda pendiente de aprobación como votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Alice es candidata aprobada de la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.eliminar_usuario_interno(env.accounts.alice),
+                Err(Error::UsuarioMiembroDeEleccion)
+            );
+
+            // Al eliminar a Charlie se descarta su solicitud pendiente y su lista de elecciones
+            env.contract.eliminar_usuario_interno(env.accounts.charlie).unwrap();
+            let eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
+            assert_eq!(eleccion.existe_usuario(&env.accounts.charlie), None);
+            assert_eq!(env.contract.elecciones_usuario.get(env.accounts.charlie), None);
+
+            // Se elimina a Bob y su DNI puede volver a registrarse desde otra cuenta
+            env.contract.eliminar_usuario_interno(env.accounts.bob).unwrap();
+            assert!(!env.contract.usuario_existe_interno(env.accounts.bob));
+            assert!(!env.contract.dni_existe_interno(String::from("22222222")));
+            assert_eq!(env.contract.get_total_usuarios_interno(), 2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            env.contract
+                .registrar_usuario_interno(
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("22222222"),
+                )
+                .unwrap();
+            assert_eq!(
+                env.contract.id_usuarios.get(String::from("22222222")),
+                Some(env.accounts.eve)
+            );
+            assert_eq!(env.contract.get_total_usuarios_interno(), 3);
+
+            // Aun finalizada la elección, Alice sigue sin poder eliminarse
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(
+                env.contract.eliminar_usuario_interno(env.accounts.alice),
+                Err(Error::UsuarioMiembroDeEleccion)
+            );
+            assert_eq!(env.contract.get_total_usuarios_interno(), 3);
+        }
+
+        #[ink::test]
+        fn probar_reportes_tras_eliminar_usuario() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Votante),
+                (env.accounts.charlie, Rol::Votante),
+            ] {
+                env.contract
+                    .registrar_y_aprobar_interno(eleccion_id, id, rol)
+                    .unwrap();
+            }
+
+            // Django queda pendiente de aprobación como votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .votar_interno(eleccion_id, env.accounts.alice, 1)
+                .unwrap();
+
+            // Finalizada la elección se elimina a Django, que nunca fue aprobado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.eliminar_usuario_interno(env.accounts.django).unwrap();
+
+            // Los datos que consulta el contrato de reportes siguen disponibles
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+            let votantes = env.contract.get_votantes_aprobados_interno(eleccion_id).unwrap();
+            assert_eq!(votantes.len(), 2);
+            for votante in votantes {
+                assert!(env.contract.get_usuarios_interno(votante.id).is_ok());
+            }
+            let resultado = env.contract.get_resultado_final_interno(eleccion_id).unwrap();
+            assert_eq!(resultado, vec![(env.accounts.alice, 1)]);
+            for (id, _) in resultado {
+                assert!(env.contract.get_usuarios_interno(id).is_ok());
+            }
+        }
+
+        #[ink::test]
+        fn probar_registrar_usuarios_batch() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Un usuario no puede registrar a otros
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.registrar_usuarios_batch_interno(Vec::new()),
+                Err(Error::PermisosInsuficientes)
+            );
 
-            // La cuenta que crea el contrato ya no es admin
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert!(!env.contract.es_admin());
+            let usuarios = vec![
+                (
+                    env.accounts.eve,
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("55555555"),
+                ),
+                (
+                    env.accounts.bob,
+                    String::from("Bob"),
+                    String::from("B"),
+                    String::from("88888888"),
+                ),
+                (
+                    env.accounts.eve,
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("99999999"),
+                ),
+                (
+                    env.accounts.frank,
+                    String::from("Frank"),
+                    String::from("F"),
+                    String::from("11111111"),
+                ),
+                (
+                    env.accounts.frank,
+                    String::from("Frank"),
+                    String::from("F"),
+                    String::from("12"),
+                ),
+                (
+                    env.accounts.frank,
+                    String::from("Frank"),
+                    String::from("F"),
+                    String::from("66666666"),
+                ),
+            ];
+            let resultados = env.contract.registrar_usuarios_batch_interno(usuarios).unwrap();
 
-            // Alice le cede los privilegios a Bob
+            assert_eq!(
+                resultados,
+                vec![
+                    (env.accounts.eve, Ok(())),
+                    // Bob ya se encuentra registrado
+                    (env.accounts.bob, Err(Error::UsuarioExistente)),
+                    // Eve aparece dos veces en el lote
+                    (env.accounts.eve, Err(Error::UsuarioExistente)),
+                    // El DNI de Frank pertenece a Alice
+                    (env.accounts.frank, Err(Error::UsuarioExistente)),
+                    (env.accounts.frank, Err(Error::DniInvalido)),
+                    (env.accounts.frank, Ok(())),
+                ]
+            );
+            assert_eq!(env.contract.get_total_usuarios_interno(), 6);
+            assert_eq!(
+                env.contract.id_usuarios.get(String::from("66666666")),
+                Some(env.accounts.frank)
+            );
+
+            // El administrador no puede registrarse como usuario
+            assert_eq!(
+                env.contract.registrar_usuarios_batch_interno(vec![(
+                    env.contract_id,
+                    String::from("Admin"),
+                    String::from("A"),
+                    String::from("77777777"),
+                )]),
+                Ok(vec![(env.contract_id, Err(Error::UsuarioNoPermitido))])
+            );
+
+            // Tampoco puede registrarse la cuenta del contrato de reportes
+            let contrato_reportes = AccountId::from([0x07; 32]);
+            env.contract
+                .establecer_contrato_reportes_interno(contrato_reportes)
+                .unwrap();
+            assert_eq!(
+                env.contract.registrar_usuarios_batch_interno(vec![(
+                    contrato_reportes,
+                    String::from("Reportes"),
+                    String::from("R"),
+                    String::from("77777777"),
+                )]),
+                Ok(vec![(contrato_reportes, Err(Error::UsuarioNoPermitido))])
+            );
+            assert!(!env.contract.usuario_existe_interno(contrato_reportes));
+            assert_eq!(env.contract.get_total_usuarios_interno(), 6);
+        }
+
+        #[ink::test]
+        fn probar_crear_eleccion_con_fin_registro() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // El registro no puede cerrar después del inicio de la elección
+            assert_eq!(
+                env.contract.crear_eleccion_con_fin_registro_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    1,
+                    30,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                ),
+                Err(Error::FechaFinRegistroInvalida)
+            );
+            assert_eq!(env.contract.elecciones.len(), 0);
+
+            // El registro cierra el 02/02/1970 00:30hs y la elección inicia a la 01:00hs
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_con_fin_registro_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    30,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Alice se registra justo antes del cierre, 02/02/1970 00:29:59hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2766599999);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(env.contract.delegar_admin_interno(env.accounts.bob).is_ok());
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
 
-            // Bob prueba si es admin
+            // Bob intenta registrarse al cierre, con la elección aún pendiente
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2766600000);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            assert!(env.contract.delegar_admin_interno(env.accounts.frank).is_ok());
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Ok(EstadoDeEleccion::Pendiente)
+            );
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::RegistroCerrado)
+            );
+        }
 
-            // Alice ya no es admin
+        #[ink::test]
+        fn probar_verificar_consistencia_usuario() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            assert_eq!(
+                env.contract.verificar_consistencia_usuario_interno(env.accounts.alice),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            assert_eq!(
+                env.contract.verificar_consistencia_usuario_interno(env.accounts.eve),
+                Err(Error::UsuarioNoExistente)
+            );
+            assert_eq!(
+                env.contract.verificar_consistencia_usuario_interno(env.accounts.alice),
+                Ok(true)
+            );
+
+            // Se corrompe el DNI de Alice, asociándolo a Bob
+            env.contract
+                .id_usuarios
+                .insert(String::from("11111111"), &env.accounts.bob);
+            assert_eq!(
+                env.contract.verificar_consistencia_usuario_interno(env.accounts.alice),
+                Ok(false)
+            );
+
+            // Se elimina el DNI de Bob
+            env.contract.id_usuarios.remove(String::from("22222222"));
+            assert_eq!(
+                env.contract.verificar_consistencia_usuario_interno(env.accounts.bob),
+                Ok(false)
+            );
+        }
+
+        #[ink::test]
+        fn probar_get_duracion_eleccion() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            assert_eq!(
+                env.contract.get_duracion_eleccion_interno(1),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Elección del 02/02/1970 01:00hs al 05/02/1970 13:30hs
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    13,
+                    30,
+                    5,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // 3 días, 12 horas y 30 minutos
+            assert_eq!(
+                env.contract.get_duracion_eleccion_interno(eleccion_id),
+                Ok(304_200_000)
+            );
+        }
+
+        #[ink::test]
+        fn probar_he_votado() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            assert_eq!(env.contract.he_votado_interno(u32::MAX), Err(Error::VotacionNoExiste));
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata, Django votante aprobado y Bob votante pendiente
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+                (env.accounts.bob, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol)
+                    .unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(env.contract.he_votado_interno(eleccion_id), Ok(false));
+
+            // Django vota a Alice
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+            assert_eq!(env.contract.he_votado_interno(eleccion_id), Ok(true));
+
+            // Ni un votante pendiente, ni un candidato, ni un usuario ajeno son votantes aprobados
+            for id in [env.accounts.bob, env.accounts.alice, env.accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                assert_eq!(
+                    env.contract.he_votado_interno(eleccion_id),
+                    Err(Error::VotanteNoExistente)
+                );
+            }
+        }
+
+        #[ink::test]
+        fn probar_revocar_aprobacion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice y Bob son candidatos aprobados, Django votante aprobado
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            // Un usuario no puede revocar aprobaciones
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(!env.contract.es_admin());
+            assert_eq!(
+                env.contract.revocar_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Candidato
+                ),
+                Err(Error::PermisosInsuficientes)
+            );
 
-            // Eve no puede delegar privilegios porque no es admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Django no es candidato, ni Charlie votante
+            assert_eq!(
+                env.contract.revocar_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Candidato
+                ),
+                Err(Error::CandidatoNoExistente)
+            );
+            assert_eq!(
+                env.contract.revocar_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.charlie,
+                    Rol::Votante
+                ),
+                Err(Error::VotanteNoExistente)
+            );
+
+            // El administrador descalifica a Bob
+            env.contract
+                .revocar_aprobacion_interno(eleccion_id, env.accounts.bob, Rol::Candidato)
+                .unwrap();
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((0, 1, 0, 1)));
             assert_eq!(
-                env.contract
-                    .delegar_admin_interno(env.accounts.alice)
-                    .unwrap_err()
-                    .to_string(),
-                Error::PermisosInsuficientes.to_string()
+                env.contract.buscar_eleccion(eleccion_id).unwrap().get_candidatos_verificados(),
+                vec![env.accounts.alice]
             );
-        }
 
-        #[ink::test]
-        fn probar_registro_sistema_admin() {
-            let mut env = ContractEnv::default();
-            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
-            // Alice es ahora admin del sistema
-            assert!(env.contract.delegar_admin_interno(env.accounts.alice).is_ok());
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
 
-            // Alice como invocante del contrato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            // Registrar a Alice en el sistema no debe ser posible
+            // Con la elección en curso ya no es posible revocar aprobaciones
             assert_eq!(
-                env.contract
-                    .registrar_usuario_interno(
-                        String::from("Alice"),
-                        String::from(""),
-                        String::from("11111111")
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::UsuarioNoPermitido.to_string()
+                env.contract.revocar_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Votante
+                ),
+                Err(Error::VotacionEnCurso)
             );
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((0, 1, 0, 1)));
         }
 
         #[ink::test]
-        fn probar_crear_eleccion() {
-            let mut env = ContractEnv::default();
+        fn probar_modo_invitacion() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Se crea una elección con exito, de id = 1
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Un usuario no puede invitar ni habilitar el modo por invitación
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
             assert_eq!(
-                env.contract
-                    .crear_eleccion_interno(
-                        String::from("Presidente"),
-                        0,
-                        0,
-                        1,
-                        1,
-                        1970,
-                        1,
-                        1,
-                        1,
-                        1,
-                        1970,
-                    )
-                    .unwrap(),
-                1
+                env.contract.invitar_interno(eleccion_id, vec![env.accounts.bob]),
+                Err(Error::PermisosInsuficientes)
             );
-            // Se crea una segunda elección con exito, de id = 2
             assert_eq!(
-                env.contract
-                    .crear_eleccion_interno(
-                        String::from("Presidente"),
-                        0,
-                        0,
-                        1,
-                        1,
-                        1970,
-                        1,
-                        1,
-                        1,
-                        1,
-                        1970,
-                    )
-                    .unwrap(),
-                2
+                env.contract.set_modo_invitacion_interno(eleccion_id, true),
+                Err(Error::PermisosInsuficientes)
             );
 
-            // La creación falla porque la fecha de finalización es posterior a la de inicio.
+            // Se invita a Alice y Bob
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .invitar_interno(eleccion_id, vec![env.accounts.alice, env.accounts.bob])
+                .unwrap();
+
+            // Con el modo deshabilitado cualquier usuario puede registrarse
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Con el modo habilitado solo los invitados pueden registrarse
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.set_modo_invitacion_interno(eleccion_id, true).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             assert_eq!(
-                env.contract
-                    .crear_eleccion_interno(
-                        String::from("Presidente"),
-                        1,
-                        1,
-                        1,
-                        1,
-                        1970,
-                        0,
-                        0,
-                        1,
-                        1,
-                        1970,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::FechaFinalizacionInvalida.to_string()
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::NoInvitado)
             );
-
-            // Bob como invocante del contrato
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            // Bob no debe poder crear una elección, puesto que no es admin
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Los ya registrados siguen obteniendo el error correspondiente, aún sin invitación
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
             assert_eq!(
-                env.contract
-                    .crear_eleccion_interno(
-                        String::from("Presidente"),
-                        0,
-                        0,
-                        1,
-                        1,
-                        1970,
-                        1,
-                        0,
-                        1,
-                        1,
-                        1970,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::PermisosInsuficientes.to_string()
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::UsuarioYaEsVotante)
+            );
+
+            // Una vez iniciada la elección ya no es posible invitar
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.invitar_interno(eleccion_id, vec![env.accounts.django]),
+                Err(Error::VotacionEnCurso)
+            );
+            assert_eq!(
+                env.contract.invitar_interno(99, vec![env.accounts.django]),
+                Err(Error::VotacionNoExiste)
             );
         }
 
         #[ink::test]
-        fn probar_registro_eleccion() {
+        fn probar_votar_write_in() {
             // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Crear una elección
             let eleccion_id = env
                 .contract
                 .crear_eleccion_interno(
@@ -917,340 +8462,419 @@ mod sistema_votacion {
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
-            // Establecer el tiempo del bloque en uno válido para registrarse
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Alice se registra en la elección como `Rol::Candidato`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(env
-                .contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .is_ok());
+            // Alice es candidata aprobada, Bob y Django votantes aprobados
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
 
-            // Bob se registra en la elección como `Rol::Candidato`
+            // Un usuario no puede habilitar los votos write-in
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            assert!(env
-                .contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .is_ok());
+            assert_eq!(
+                env.contract.set_permite_write_in_interno(eleccion_id, true),
+                Err(Error::PermisosInsuficientes)
+            );
 
-            // Charlie se registra en la elección como `Rol::Votante`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
-            assert!(env
-                .contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
-                .is_ok());
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
 
-            // Django se confundió el id de la elección
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            // Con los votos write-in deshabilitados, Bob no puede votar a Django
             assert_eq!(
-                env.contract
-                    .registrar_en_eleccion_interno(u32::MAX, Rol::Votante)
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionNoExiste.to_string()
+                env.contract.votar_write_in_interno(eleccion_id, env.accounts.django),
+                Err(Error::WriteInNoPermitido)
             );
 
-            // Eve se intenta registrar en la elección, pero no existe en el sistema
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            // Una vez iniciada la elección ya no es posible habilitarlos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
             assert_eq!(
-                env.contract
-                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
-                    .unwrap_err()
-                    .to_string(),
-                Error::UsuarioNoExistente.to_string()
+                env.contract.set_permite_write_in_interno(eleccion_id, true),
+                Err(Error::VotacionEnCurso)
             );
 
-            // Alice olvidó que ya se había registrado, e intenta volver a hacerlo
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert_eq!(
+            // Se crea una segunda elección con los votos write-in habilitados
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            env.contract.set_permite_write_in_interno(eleccion_id, true).unwrap();
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Votante),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
                 env.contract
-                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                    .unwrap_err()
-                    .to_string(),
-                Error::MiembroExistente.to_string()
-            );
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
 
-            // Charlie olvidó que ya se había registrado, e intenta volver a hacerlo
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Bob vota a Django, que se incorpora como candidato con un voto
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .votar_write_in_interno(eleccion_id, env.accounts.django)
+                .unwrap();
+            let eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
             assert_eq!(
-                env.contract
-                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
-                    .unwrap_err()
-                    .to_string(),
-                Error::MiembroExistente.to_string()
+                eleccion.get_candidatos_verificados(),
+                vec![env.accounts.alice, env.accounts.django]
+            );
+            assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+
+            // Bob no puede volver a votar
+            assert_eq!(
+                env.contract.votar_write_in_interno(eleccion_id, env.accounts.django),
+                Err(Error::VotanteYaVoto)
             );
         }
 
         #[ink::test]
-        fn probar_registro_eleccion_tiempo() {
+        fn probar_set_gracia_registro() {
             // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Crear una elección
+            // Elección que inicia el 02/02/1970 01:00hs
             let eleccion_id = env
                 .contract
                 .crear_eleccion_interno(
                     String::from("Presidente"),
-                    // inicio: 31/1/1970 00:00hs
-                    0,
-                    0,
-                    31,
                     1,
-                    1970,
-                    // fin: 1/2/1970 00:00hs
                     0,
+                    2,
+                    2,
+                    1970,
+                    2,
                     0,
-                    1,
+                    2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
+            let inicio = env.contract.buscar_eleccion(eleccion_id).unwrap().inicio.get_tiempo_unix();
 
-            // Establecer el tiempo del bloque en uno previo al inicio
-            // 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Alice se registra en la elección como `Rol::Candidato`.
+            // Un usuario no puede establecer el margen de gracia
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(env
-                .contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .is_ok());
+            assert_eq!(
+                env.contract.set_gracia_registro_interno(eleccion_id, 60_000),
+                Err(Error::PermisosInsuficientes)
+            );
 
-            // Establecer el tiempo al último instante antes del inicio de la eleccion.
-            // 31/01/1970 23:59hs
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2678340000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .set_gracia_registro_interno(eleccion_id, 60_000)
+                .unwrap();
 
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
-            // Ahora Charlie no puede registrarse
+            // Alice se registra dentro del margen de gracia
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(inicio + 30_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
             assert_eq!(
-                env.contract
-                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionEnCurso.to_string()
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Ok(())
             );
 
-            // Establecer el tiempo del bloque al primer instante **inválido**, tras finalizar
-            // 01/02/1970 00:00hs
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2678400000);
-
-            // Bob se olvidó de registrarse, y no puede realizarlo porque la elección finalizó
+            // Bob intenta registrarse una vez vencido el margen
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(inicio + 60_000);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
             assert_eq!(
-                env.contract
-                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionFinalizada.to_string()
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Votante),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Ya iniciada la elección no es posible modificar el margen
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.set_gracia_registro_interno(eleccion_id, 120_000),
+                Err(Error::VotacionEnCurso)
             );
+            assert_eq!(env.contract.get_conteos_interno(eleccion_id), Ok((1, 0, 0, 0)));
         }
 
         #[ink::test]
-        fn probar_consultar_miembros_no_verificados() {
+        fn probar_aprobar_en_gracia_registro() {
+            // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Crear una elección
+            // Elección que inicia el 02/02/1970 01:00hs
             let eleccion_id = env
                 .contract
                 .crear_eleccion_interno(
                     String::from("Presidente"),
-                    0,
+                    1,
                     0,
                     2,
                     2,
                     1970,
-                    1,
+                    2,
                     0,
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
+            let inicio = env
+                .contract
+                .buscar_eleccion(eleccion_id)
+                .unwrap()
+                .inicio
+                .get_tiempo_unix();
 
-            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            // Alice es candidata aprobada antes del inicio, con un margen de un minuto
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
-
-            // Como la elección no tiene miembros, retorna un vector vacío
-            assert_eq!(
-                env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
-                    .unwrap(),
-                vec![]
-            );
-            assert_eq!(
-                env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
-                    .unwrap(),
-                vec![]
-            );
-
-            // Alice se registra como Candidato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
             env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.alice, Rol::Candidato)
                 .unwrap();
-
-            // Bob se registra como Votante
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
             env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .set_gracia_registro_interno(eleccion_id, 60_000)
                 .unwrap();
 
-            // El único candidato no verificado es Alice
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert_eq!(
+            // Bob y Charlie se registran dentro del margen de gracia
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(inicio + 10_000);
+            for id in [env.accounts.bob, env.accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
                 env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
-                    .unwrap()
-                    .first()
-                    .unwrap()
-                    .0,
-                env.accounts.alice
-            );
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                    .unwrap();
+            }
 
-            // El único votante no verificado es Charlie
+            // El administrador aprueba a Bob, de forma individual, y a Charlie en lote
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
             assert_eq!(
                 env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
-                    .unwrap()
-                    .first()
-                    .unwrap()
-                    .0,
-                env.accounts.charlie
+                    .aprobar_miembros_batch_interno(
+                        eleccion_id,
+                        vec![(env.accounts.charlie, Rol::Votante)]
+                    )
+                    .unwrap(),
+                vec![(env.accounts.charlie, Ok(()))]
             );
 
-            // Eve no puede obtener los miembros de una elección no verificados
-            // porque no es admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
-            assert_eq!(
+            // Django es registrado y aprobado por el administrador durante el margen
+            env.contract
+                .registrar_y_aprobar_interno(eleccion_id, env.accounts.django, Rol::Votante)
+                .unwrap();
+
+            // Los miembros aprobados durante el margen pueden votar
+            for id in [env.accounts.bob, env.accounts.charlie, env.accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
                 env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
-                    .unwrap_err()
-                    .to_string(),
-                Error::PermisosInsuficientes.to_string()
-            );
+                    .votar_interno(eleccion_id, env.accounts.alice, 1)
+                    .unwrap();
+            }
 
-            // El admin intenta obtener una elección inexistente
+            // Vencido el margen ya no es posible aprobar miembros
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(inicio + 60_000);
             assert_eq!(
-                env.contract
-                    .consultar_miembros_no_verificados_interno(u32::MAX, Rol::Votante)
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionNoExiste.to_string()
+                env.contract.cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.bob,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                ),
+                Err(Error::VotacionEnCurso)
+            );
+            assert_eq!(
+                env.contract.get_conteos_interno(eleccion_id),
+                Ok((0, 3, 0, 1))
             );
         }
 
         #[ink::test]
-        fn probar_consultar_candidatos_disponibles() {
+        fn probar_cancelar_eleccion() {
+            // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Crear una elección
+            // Crear dos elecciones
             let eleccion_id = env
                 .contract
                 .crear_eleccion_interno(
                     String::from("Presidente"),
+                    1,
                     0,
+                    2,
+                    2,
+                    1970,
+                    2,
                     0,
                     2,
                     2,
                     1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+            let eleccion_id2 = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Gobernador"),
                     1,
                     0,
                     2,
                     2,
                     1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
             // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Como la elección no tiene miembros, retorna un vector vacío
-            assert_eq!(
-                env.contract
-                    .consultar_candidatos_disponibles_interno(eleccion_id)
-                    .unwrap(),
-                vec![]
-            );
-
-            // Alice se registra como Candidato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            // Django se registra como Votante en la primera elección y es aprobado
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
                 .unwrap();
-
-            // Bob se registra como Votante
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
             env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
                 .unwrap();
 
+            // Eve no puede cancelar la elección porque no es admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.cancelar_eleccion_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // El admin intenta cancelar una elección inexistente
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            // Alice no se ha aprobado, por lo que continua vacia. El admin posee permisos para el método
+            assert_eq!(
+                env.contract.cancelar_eleccion_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // El admin cancela la primera elección
+            assert!(env.contract.cancelar_eleccion_interno(eleccion_id).is_ok());
+            assert_eq!(
+                env.contract.cancelar_eleccion_interno(eleccion_id),
+                Err(Error::VotacionCancelada)
+            );
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id),
+                Err(Error::VotacionCancelada)
+            );
+
+            // Alice ya no puede registrarse en la elección cancelada
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
             assert_eq!(
                 env.contract
-                    .consultar_candidatos_disponibles_interno(eleccion_id)
-                    .unwrap(),
-                vec![]
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato),
+                Err(Error::VotacionCancelada)
             );
 
-            // Se aprueba a Alice
+            // La segunda elección conserva su id y sigue admitiendo registros
             assert!(env
                 .contract
-                .cambiar_estado_aprobacion_interno(
-                    eleccion_id,
-                    env.accounts.alice,
-                    Rol::Candidato,
-                    EstadoAprobacion::Aprobado,
-                )
+                .registrar_en_eleccion_interno(eleccion_id2, Rol::Candidato)
                 .is_ok());
+            assert_eq!(
+                env.contract.consultar_estado_interno(eleccion_id2),
+                Ok(EstadoDeEleccion::Pendiente)
+            );
+            assert_eq!(
+                env.contract.listar_elecciones_interno(),
+                vec![(eleccion_id2, EstadoDeEleccion::Pendiente)]
+            );
 
-            // Se rechaza a Bob
-            assert!(env
-                .contract
-                .cambiar_estado_aprobacion_interno(
-                    eleccion_id,
-                    env.accounts.bob,
-                    Rol::Votante,
-                    EstadoAprobacion::Rechazado,
-                )
-                .is_ok());
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
 
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            // Se verifica que el AccountId de Alice se encuentre en los candidatos disponibles (verifica la propia Alice)
+            // Django no puede votar en la elección cancelada
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             assert_eq!(
-                env.contract
-                    .consultar_candidatos_disponibles_interno(eleccion_id)
-                    .unwrap()
-                    .first()
-                    .unwrap()
-                    .0,
-                env.accounts.alice
+                env.contract.votar_en_blanco_interno(eleccion_id),
+                Err(Error::VotacionCancelada)
             );
 
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            // Bob intenta ver los candidatos disponibles. No es posible porque fue rechazado de la elección
-            assert!(env
-                .contract
-                .consultar_candidatos_disponibles_interno(eleccion_id)
-                .is_err());
+            // No es posible cancelar una elección en curso
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert_eq!(
+                env.contract.cancelar_eleccion_interno(eleccion_id2),
+                Err(Error::VotacionEnCurso)
+            );
         }
 
         #[ink::test]
-        fn probar_estado_aprobacion() {
+        fn probar_votar() {
             // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
@@ -1263,217 +8887,235 @@ mod sistema_votacion {
                     String::from("Presidente"),
                     1,
                     0,
-                    1,
-                    1,
+                    2,
+                    2,
                     1970,
                     2,
                     0,
-                    1,
-                    1,
+                    2,
+                    2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
+            // establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Intento votar en una eleccion que no existe
+            assert_eq!(
+                env.contract.votar_interno(u32::MAX, env.accounts.bob, 1),
+                Err(Error::VotacionNoExiste)
+            );
+
             // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
             // Alice se registra en la elección como `Rol::Candidato`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
-            assert!(env
-                .contract
+            env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .is_ok());
+                .unwrap();
 
             // Bob se registra en la elección como `Rol::Candidato`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            assert!(env
-                .contract
+            env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .is_ok());
+                .unwrap();
 
-            // Charlie se registra en la elección como `Rol::Votante`
+            // Charlie se registra en la elección como `Rol::Candidato`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
-            assert!(env
-                .contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
-                .is_ok());
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
 
             // Django se registra en la elección como `Rol::Votante`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
-            assert!(env
-                .contract
+            env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
-                .is_ok());
+                .unwrap();
 
-            // Alice y Bob están pendientes de verificación como Candidatos
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert_eq!(
-                env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Candidato)
-                    .unwrap()
-                    .iter()
-                    .map(|m| m.0)
-                    .collect::<Vec<_>>(),
-                vec![env.accounts.alice, env.accounts.bob]
-            );
-
-            // Charlie y Django están pendientes de verificación como Votantes
-            assert_eq!(
-                env.contract
-                    .consultar_miembros_no_verificados_interno(eleccion_id, Rol::Votante)
-                    .unwrap()
-                    .iter()
-                    .map(|m| m.0)
-                    .collect::<Vec<_>>(),
-                vec![env.accounts.charlie, env.accounts.django]
-            );
-
             // Admin aprueba a Alice como Candidato
-            assert!(env
-                .contract
+            env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
                     env.accounts.alice,
                     Rol::Candidato,
                     EstadoAprobacion::Aprobado,
                 )
-                .is_ok());
+                .unwrap();
 
             // Admin rechaza a Bob como Candidato
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert!(env
-                .contract
+            env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
                     env.accounts.bob,
                     Rol::Candidato,
                     EstadoAprobacion::Rechazado,
                 )
-                .is_ok());
+                .unwrap();
 
-            // Admin aprueba a Charlie como Votante
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert!(env
-                .contract
+            // Admin aprueba a Django como Votante
+            env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
-                    env.accounts.charlie,
+                    env.accounts.django,
                     Rol::Votante,
                     EstadoAprobacion::Aprobado,
                 )
-                .is_ok());
+                .unwrap();
 
-            // Admin rechaza a Django como Votante
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert!(env
-                .contract
-                .cambiar_estado_aprobacion_interno(
-                    eleccion_id,
-                    env.accounts.django,
-                    Rol::Votante,
-                    EstadoAprobacion::Rechazado,
-                )
-                .is_ok());
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
 
-            // Admin no puede aprobar a Alice como Votante, ya que no está registrada en ese rol
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Intento votar en una eleccion con un votante invalido
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.frank);
             assert_eq!(
-                env.contract
-                    .cambiar_estado_aprobacion_interno(
-                        eleccion_id,
-                        env.accounts.alice,
-                        Rol::Votante,
-                        EstadoAprobacion::Aprobado,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotanteNoExistente.to_string()
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 1),
+                Err(Error::VotanteNoExistente)
             );
 
-            // Admin no puede aprobar a Frank porque no está registrado
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Intento votar a un candidato invalido en una eleccion
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             assert_eq!(
-                env.contract
-                    .cambiar_estado_aprobacion_interno(
-                        eleccion_id,
-                        env.accounts.frank,
-                        Rol::Candidato,
-                        EstadoAprobacion::Rechazado,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::CandidatoNoExistente.to_string()
+                env.contract.votar_interno(eleccion_id, env.accounts.frank, 1),
+                Err(Error::CandidatoNoExistente)
             );
 
-            // Admin no puede aprobar a Frank porque se confundió el id de elección
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            // Django vota a Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+
+            // Django reenvía la misma transacción, que no suma un nuevo voto
             assert_eq!(
-                env.contract
-                    .cambiar_estado_aprobacion_interno(
-                        u32::MAX,
-                        env.accounts.django,
-                        Rol::Votante,
-                        EstadoAprobacion::Rechazado,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionNoExiste.to_string()
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 1),
+                Ok(())
             );
+            let eleccion = env.contract.buscar_eleccion(eleccion_id).unwrap();
+            assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 1);
 
-            // Eve no puede cambiar el estado de aprobación, porque no es admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            // Django intenta volver a votar a Alice
             assert_eq!(
-                env.contract
-                    .cambiar_estado_aprobacion_interno(
-                        eleccion_id,
-                        env.accounts.alice,
-                        Rol::Candidato,
-                        EstadoAprobacion::Aprobado,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::PermisosInsuficientes.to_string(),
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 2),
+                Err(Error::VotanteYaVoto)
             );
 
-            // Establecer el tiempo para que la eleccion este en curso
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(5400000);
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
 
-            // Admin no puede aprobar a Django porque la elección esta en curso
+            // Pido los candidatos aprobados
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            let candidatos = env
+                .contract
+                .consultar_candidatos_disponibles_interno(eleccion_id)
+                .unwrap();
+            let alice_id = env.accounts.alice;
+            let alice = env.contract.usuarios.get(alice_id).unwrap();
+            // La candidata debe ser Alice ya que es la unica aprobada
+            let response = vec![(alice_id, alice.nombre, alice.apellido)];
+            assert_eq!(candidatos, response);
+        }
+
+        #[ink::test]
+        fn probar_cambiar_voto() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Intento cambiar el voto en una eleccion que no existe
             assert_eq!(
-                env.contract
-                    .cambiar_estado_aprobacion_interno(
-                        eleccion_id,
-                        env.accounts.django,
-                        Rol::Votante,
-                        EstadoAprobacion::Rechazado,
-                    )
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionEnCurso.to_string()
+                env.contract.cambiar_voto_interno(u32::MAX, env.accounts.alice),
+                Err(Error::VotacionNoExiste)
             );
 
-            // Establecer el tiempo en uno posterior a la elección
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(1700000000);
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Admin no puede aprobar a Django porque la elección finalizó
+            // Alice y Bob se registran como Candidatos, Django como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                .unwrap();
+
+            // Admin aprueba a todos
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert_eq!(
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.bob, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
                 env.contract
                     .cambiar_estado_aprobacion_interno(
                         eleccion_id,
-                        env.accounts.django,
-                        Rol::Votante,
-                        EstadoAprobacion::Rechazado,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
                     )
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionFinalizada.to_string()
+                    .unwrap();
+            }
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Django intenta cambiar su voto antes de votar
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.cambiar_voto_interno(eleccion_id, env.accounts.bob),
+                Err(Error::VotanteNoHaVotado)
+            );
+
+            // Django vota a Alice y luego cambia su voto a Bob
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+            assert!(env
+                .contract
+                .cambiar_voto_interno(eleccion_id, env.accounts.bob)
+                .is_ok());
+
+            let eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 0);
+            assert_eq!(eleccion.candidatos_aprobados[1].get_votos(), 1);
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(
+                env.contract.cambiar_voto_interno(eleccion_id, env.accounts.alice),
+                Err(Error::VotacionFinalizada)
             );
         }
 
         #[ink::test]
-        fn probar_get_candidatos() {
+        fn probar_votar_en_blanco() {
             // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
@@ -1494,69 +9136,34 @@ mod sistema_votacion {
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
-            // establecer con fines de pruebas el id del contrato reportes igual al administrador
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
-
-            // Intento pedir los candidatos de una eleccion que no existe
+            // Intento votar en blanco en una eleccion que no existe
             assert_eq!(
-                env.contract
-                    .get_candidatos_interno(u32::MAX)
-                    .unwrap_err()
-                    .to_string(),
-                Error::VotacionNoExiste {}.to_string()
+                env.contract.votar_en_blanco_interno(u32::MAX),
+                Err(Error::VotacionNoExiste)
             );
 
-            // Intento pedir los candidatos de una eleccion sin candidatos
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
-            assert!(env.contract.get_candidatos_interno(eleccion_id).unwrap().is_empty());
-
             // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Intento pedir los candidatos antes de que inicie la eleccion
-            assert_eq!(
-                env.contract.get_candidatos_interno(eleccion_id),
-                Err(Error::VotacionNoIniciada)
-            );
-
             // Alice se registra en la elección como `Rol::Candidato`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
             env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
                 .unwrap();
 
-            // Bob se registra en la elección como `Rol::Candidato`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .unwrap();
-
-            // Charlie se registra en la elección como `Rol::Candidato`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            // Django se registra en la elección como `Rol::Votante`
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
                 .unwrap();
 
-            // Intento pedir los candidatos mientras la eleccion esta en curso
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2770200000);
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            assert_eq!(
-                env.contract.get_candidatos_interno(eleccion_id),
-                Err(Error::VotacionEnCurso)
-            );
-
-            // Intento pedir los candidatos de una eleccion sin candidatos aprobados
+            // Admin aprueba a Alice como Candidato y a Django como Votante
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
-            assert!(env.contract.get_candidatos_interno(eleccion_id).unwrap().is_empty());
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
-
-            // Admin aprueba a Alice como Candidato
             env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
@@ -1565,45 +9172,111 @@ mod sistema_votacion {
                     EstadoAprobacion::Aprobado,
                 )
                 .unwrap();
-
-            // Admin rechaza a Bob como Candidato
             env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
-                    env.accounts.bob,
-                    Rol::Candidato,
-                    EstadoAprobacion::Rechazado,
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
                 )
                 .unwrap();
 
-            // Admin aprueba a Charlie como Candidato
-            env.contract
-                .cambiar_estado_aprobacion_interno(
-                    eleccion_id,
-                    env.accounts.charlie,
-                    Rol::Candidato,
-                    EstadoAprobacion::Aprobado,
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+
+            // Frank no es votante de la elección
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.frank);
+            assert_eq!(
+                env.contract.votar_en_blanco_interno(eleccion_id),
+                Err(Error::VotanteNoExistente)
+            );
+
+            // Django vota en blanco
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_en_blanco_interno(eleccion_id).unwrap();
+
+            // Django intenta volver a votar
+            assert_eq!(
+                env.contract.votar_en_blanco_interno(eleccion_id),
+                Err(Error::VotanteYaVoto)
+            );
+            assert_eq!(
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 1),
+                Err(Error::VotanteYaVoto)
+            );
+
+            // El voto de Django cuenta para la participación pero Alice no recibió votos
+            let eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            assert_eq!(eleccion.votantes_aprobados[0].get_votos(), 1);
+            assert_eq!(eleccion.candidatos_aprobados[0].get_votos(), 0);
+
+            // Solo el contrato de reportes consulta los votos en blanco, una vez finalizada
+            assert_eq!(
+                env.contract.get_votos_en_blanco_interno(eleccion_id),
+                Err(Error::PermisosInsuficientes)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+            assert_eq!(
+                env.contract.get_votos_en_blanco_interno(eleccion_id),
+                Err(Error::VotacionEnCurso)
+            );
+
+            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
+            assert_eq!(env.contract.get_votos_en_blanco_interno(eleccion_id), Ok(1));
+        }
+
+        #[ink::test]
+        fn probar_evento_voto_emitido() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
-            // Pido los candidatos aprobados
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(99999999999);
-            let candidatos = env.contract.get_candidatos_interno(eleccion_id).unwrap();
-            let alice = env.accounts.alice;
-            let charlie = env.accounts.charlie;
-            // Los candidatos deben ser Alice y Charlie ya que son los unicos aprobados
-            let mut response = vec![Candidato::new(alice), Candidato::new(charlie)];
-            assert_eq!(candidatos, response);
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Django se registra en la elección como `Rol::Votante`
+            // Alice se registra como Candidato y Django como Votante
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            env.contract
+                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
+                .unwrap();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
                 .unwrap();
 
-            // Admin aprueba a Django como Votante
+            // Admin aprueba a ambos
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
             env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
@@ -1616,42 +9289,38 @@ mod sistema_votacion {
             // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
 
-            // Django vota a Alice
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
-            //assert!(
-            env.contract.votar_interno(eleccion_id, env.accounts.alice).unwrap();
-
-            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
-
-            // Ahora alice tiene un voto
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            let _ = response[0].votar();
-            assert_eq!(env.contract.get_candidatos_interno(eleccion_id).unwrap(), response);
-        }
-
-        #[ink::test]
-        fn probar_consultar_estado() {
-            // inicializar sistema con usuarios registrados
-            let env = ContractEnv::new_inicializado();
+            // Las aprobaciones emiten sus propios eventos
+            let aprobaciones = ink::env::test::recorded_events().count();
 
-            // Probar consultar el estado de una eleccion que no existe
-            assert_eq!(
-                env.contract.consultar_estado_interno(u32::MAX),
-                Err(Error::VotacionNoExiste)
-            );
+            // Un voto fallido no emite eventos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.frank);
+            assert!(env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).is_err());
+            assert_eq!(ink::env::test::recorded_events().count(), aprobaciones);
 
-            // Los otros casos de consultar_estado() ya fueron cubiertos en los tests anteriores
+            // Django vota a Alice, se emite un único evento
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).unwrap();
+            let eventos: Vec<_> = ink::env::test::recorded_events().skip(aprobaciones).collect();
+            assert_eq!(eventos.len(), 1);
+
+            let evento =
+                <VotoEmitido as ink::scale::Decode>::decode(&mut &eventos[0].data[..]).unwrap();
+            assert_eq!(evento.id_eleccion, eleccion_id);
+            assert_eq!(evento.votante, env.accounts.django);
+
+            // Django reenvía su voto o intenta volver a votar, no se emiten nuevos eventos
+            assert!(env.contract.votar_interno(eleccion_id, env.accounts.alice, 1).is_ok());
+            assert!(env.contract.votar_interno(eleccion_id, env.accounts.alice, 2).is_err());
+            assert_eq!(ink::env::test::recorded_events().count(), aprobaciones + 1);
         }
 
         #[ink::test]
-        fn probar_votar() {
+        fn probar_evento_estado_aprobacion_cambiado() {
             // inicializar sistema con usuarios registrados
             let mut env = ContractEnv::new_inicializado();
             ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
-            // Crear una elección
             let eleccion_id = env
                 .contract
                 .crear_eleccion_interno(
@@ -1666,119 +9335,108 @@ mod sistema_votacion {
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
-            // establecer con fines de pruebas el id del contrato reportes igual al administrador
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
-
-            // Intento votar en una eleccion que no existe
-            assert_eq!(
-                env.contract.votar_interno(u32::MAX, env.accounts.bob),
-                Err(Error::VotacionNoExiste)
-            );
-
             // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
             ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
 
-            // Alice se registra en la elección como `Rol::Candidato`
+            // Alice se registra como Candidato y Django como Votante
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
             env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
                 .unwrap();
-
-            // Bob se registra en la elección como `Rol::Candidato`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.bob);
-            env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .unwrap();
-
-            // Charlie se registra en la elección como `Rol::Candidato`
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.charlie);
-            env.contract
-                .registrar_en_eleccion_interno(eleccion_id, Rol::Candidato)
-                .unwrap();
-
-            // Django se registra en la elección como `Rol::Votante`
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
             env.contract
                 .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
                 .unwrap();
 
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            // Admin aprueba a Alice como Candidato
-            env.contract
+            // Un usuario sin permisos no emite eventos
+            assert!(env
+                .contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
                     env.accounts.alice,
                     Rol::Candidato,
                     EstadoAprobacion::Aprobado,
                 )
-                .unwrap();
+                .is_err());
 
-            // Admin rechaza a Bob como Candidato
-            env.contract
+            // Aprobar a un miembro inexistente no emite eventos
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            assert!(env
+                .contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
                     env.accounts.bob,
                     Rol::Candidato,
-                    EstadoAprobacion::Rechazado,
+                    EstadoAprobacion::Aprobado,
                 )
-                .unwrap();
+                .is_err());
+            assert!(ink::env::test::recorded_events().next().is_none());
 
-            // Admin aprueba a Django como Votante
+            // Admin aprueba a Alice y rechaza a Django
+            env.contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                )
+                .unwrap();
             env.contract
                 .cambiar_estado_aprobacion_interno(
                     eleccion_id,
                     env.accounts.django,
                     Rol::Votante,
-                    EstadoAprobacion::Aprobado,
+                    EstadoAprobacion::Rechazado,
                 )
                 .unwrap();
 
-            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
-
-            // Intento votar en una eleccion con un votante invalido
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.frank);
-            assert_eq!(
-                env.contract.votar_interno(eleccion_id, env.accounts.alice),
-                Err(Error::VotanteNoExistente)
-            );
-
-            // Intento votar a un candidato invalido en una eleccion
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
-            assert_eq!(
-                env.contract.votar_interno(eleccion_id, env.accounts.frank),
-                Err(Error::CandidatoNoExistente)
-            );
-
-            // Django vota a Alice
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
-            env.contract.votar_interno(eleccion_id, env.accounts.alice).unwrap();
-
-            // Django intenta volver a votar a Alice
+            // Aprobar nuevamente a Alice retorna un error descriptivo
             assert_eq!(
-                env.contract.votar_interno(eleccion_id, env.accounts.alice),
-                Err(Error::VotanteYaVoto)
+                env.contract.cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.alice,
+                    Rol::Candidato,
+                    EstadoAprobacion::Aprobado,
+                ),
+                Err(Error::MiembroYaAprobado)
             );
 
-            // Establecer el tiempo del bloque en uno en que la elección haya finalizado
-            ink::env::test::set_block_timestamp::<DefaultEnvironment>(9999999999);
-
-            // Pido los candidatos aprobados
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            let candidatos = env
-                .contract
-                .consultar_candidatos_disponibles_interno(eleccion_id)
-                .unwrap();
-            let alice_id = env.accounts.alice;
-            let alice = env.contract.usuarios.get(alice_id).unwrap();
-            // La candidata debe ser Alice ya que es la unica aprobada
-            let response = vec![(alice_id, alice.nombre, alice.apellido)];
-            assert_eq!(candidatos, response);
+            let eventos: Vec<_> = ink::env::test::recorded_events().collect();
+            assert_eq!(eventos.len(), 2);
+
+            let evento = <EstadoAprobacionCambiado as ink::scale::Decode>::decode(
+                &mut &eventos[0].data[..],
+            )
+            .unwrap();
+            assert_eq!(evento.id_eleccion, eleccion_id);
+            assert_eq!(evento.miembro, env.accounts.alice);
+            assert_eq!(evento.rol, Rol::Candidato);
+            assert_eq!(evento.estado, EstadoAprobacion::Aprobado);
+
+            let evento = <EstadoAprobacionCambiado as ink::scale::Decode>::decode(
+                &mut &eventos[1].data[..],
+            )
+            .unwrap();
+            assert_eq!(evento.miembro, env.accounts.django);
+            assert_eq!(evento.rol, Rol::Votante);
+            assert_eq!(evento.estado, EstadoAprobacion::Rechazado);
+
+            // Django ya fue rechazado, no se emiten nuevos eventos
+            assert!(env
+                .contract
+                .cambiar_estado_aprobacion_interno(
+                    eleccion_id,
+                    env.accounts.django,
+                    Rol::Votante,
+                    EstadoAprobacion::Aprobado,
+                )
+                .is_err());
+            assert_eq!(ink::env::test::recorded_events().count(), 2);
         }
 
         #[ink::test]
@@ -1826,13 +9484,13 @@ mod sistema_votacion {
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
             // establecer con fines de pruebas el id del contrato reportes igual al administrador
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
 
             // Intento llamar al metodo con una eleccion que no existe
             assert_eq!(
@@ -1926,6 +9584,142 @@ mod sistema_votacion {
             assert_eq!(info_votantes, response);
         }
 
+        #[ink::test]
+        fn probar_consultar_info_votantes_aprobados_pagina() {
+            // inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice, Bob y Charlie se registran como votantes y son aprobados en ese orden
+            let votantes = [env.accounts.alice, env.accounts.bob, env.accounts.charlie];
+            for id in votantes {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, Rol::Votante)
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        Rol::Votante,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+            let info: Vec<(AccountId, Usuario)> = votantes
+                .iter()
+                .map(|id| (*id, env.contract.usuarios.get(id).unwrap()))
+                .collect();
+
+            // Primera página
+            assert_eq!(
+                env.contract
+                    .consultar_info_votantes_aprobados_pagina_interno(eleccion_id, 0, 2),
+                Ok(info[0..2].to_vec())
+            );
+
+            // Última página, incompleta
+            assert_eq!(
+                env.contract
+                    .consultar_info_votantes_aprobados_pagina_interno(eleccion_id, 2, 2),
+                Ok(info[2..].to_vec())
+            );
+
+            // Posición fuera de rango
+            assert_eq!(
+                env.contract
+                    .consultar_info_votantes_aprobados_pagina_interno(eleccion_id, 5, 2),
+                Ok(vec![])
+            );
+
+            assert_eq!(
+                env.contract
+                    .consultar_info_votantes_aprobados_pagina_interno(u32::MAX, 0, 2),
+                Err(Error::VotacionNoExiste)
+            );
+
+            // Django no es administrador
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract
+                    .consultar_info_votantes_aprobados_pagina_interno(eleccion_id, 0, 2),
+                Err(Error::PermisosInsuficientes)
+            );
+        }
+
+        #[ink::test]
+        fn probar_consultar_info_votantes_aprobados_usuario_inexistente() {
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Crear una elección
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Se aprueba directamente en la elección a Eve como votante y
+            // a Frank como candidato, ninguno registrado en el sistema
+            let mut eleccion = env.contract.elecciones.get(eleccion_id - 1).unwrap();
+            eleccion
+                .votantes_aprobados
+                .push(Votante::new(env.accounts.eve));
+            eleccion
+                .candidatos_aprobados
+                .push(Candidato::new(env.accounts.frank));
+            env.contract.elecciones.set(eleccion_id - 1, &eleccion);
+
+            // El estado inconsistente se informa como error, sin producir panic
+            assert_eq!(
+                env.contract.consultar_info_votantes_aprobados_interno(eleccion_id),
+                Err(Error::UsuarioNoExistente)
+            );
+            assert_eq!(
+                env.contract.consultar_candidatos_disponibles_interno(eleccion_id),
+                Err(Error::UsuarioNoExistente)
+            );
+        }
+
         #[ink::test]
         fn probar_get_votantes_aprobados() {
             // Inicializar sistema con usuarios registrados
@@ -1948,13 +9742,13 @@ mod sistema_votacion {
                     2,
                     2,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
             // Establecer con fines de pruebas el id del contrato reportes igual al administrador
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
 
             // Intento llamar al metodo con una eleccion que no existe
             assert_eq!(
@@ -2053,9 +9847,7 @@ mod sistema_votacion {
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
 
             // Establecer con fines de pruebas el id del contrato reportes igual al administrador
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
 
             // Intento llamar al metodo con un usuario que no existe
             assert_eq!(
@@ -2080,6 +9872,39 @@ mod sistema_votacion {
             assert_eq!(env.contract.get_usuarios_interno(charlie_id).unwrap(), charlie);
         }
 
+        #[ink::test]
+        fn probar_get_usuario_por_dni() {
+            // Inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Establecer con fines de pruebas el id del contrato reportes igual al administrador
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
+
+            // Intento obtener un usuario con un DNI que no existe
+            assert_eq!(
+                env.contract.get_usuario_por_dni_interno(String::from("99999999")),
+                Err(Error::UsuarioNoExistente)
+            );
+
+            // Django intenta llamar al metodo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.get_usuario_por_dni_interno(String::from("22222222")),
+                Err(Error::PermisosInsuficientes)
+            );
+
+            // Llamo al metodo correctamente, el DNI de Bob es 22222222
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            let bob_id = env.accounts.bob;
+            let bob = env.contract.usuarios.get(bob_id).unwrap();
+            assert_eq!(
+                env.contract.get_usuario_por_dni_interno(String::from("22222222")),
+                Ok((bob_id, bob))
+            );
+        }
+
         #[ink::test]
         fn probar_establecer_contrato_reportes() {
             // Inicializar sistema con usuarios registrados
@@ -2096,8 +9921,195 @@ mod sistema_votacion {
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
             // Llamo al metodo correctamente
             env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
+                .establecer_contrato_reportes_interno(env.accounts.alice)
+                .unwrap();
+            assert_eq!(env.contract.contrato_reportes, Some(env.accounts.alice));
+        }
+
+        #[ink::test]
+        fn probar_contrato_reportes_no_admin() {
+            // Inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // El administrador no puede ser a su vez el contrato de reportes
+            assert_eq!(
+                env.contract.establecer_contrato_reportes_interno(env.contract_id),
+                Err(Error::ContratoReportesInvalido)
+            );
+
+            // Tampoco otro administrador del sistema
+            env.contract.agregar_admin_interno(env.accounts.eve).unwrap();
+            assert_eq!(
+                env.contract.establecer_contrato_reportes_interno(env.accounts.eve),
+                Err(Error::ContratoReportesInvalido)
+            );
+            assert_eq!(env.contract.contrato_reportes, None);
+        }
+
+        #[ink::test]
+        fn probar_contrato_reportes_no_es_el_propio_contrato() {
+            // Inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // El propio contrato de votación no puede ser el de reportes, aunque no sea administrador
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.accounts.charlie);
+            assert_eq!(
+                env.contract.establecer_contrato_reportes_interno(env.accounts.charlie),
+                Err(Error::ContratoReportesInvalido)
+            );
+            assert_eq!(env.contract.contrato_reportes, None);
+        }
+
+        #[ink::test]
+        fn probar_admin_no_es_contrato_reportes() {
+            // Inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.alice)
+                .unwrap();
+
+            // El contrato de reportes no puede agregarse como administrador
+            assert_eq!(
+                env.contract.agregar_admin_interno(env.accounts.alice),
+                Err(Error::ContratoReportesInvalido)
+            );
+
+            // Ni aceptar los privilegios que se le hayan delegado
+            env.contract.delegar_admin_interno(env.accounts.alice).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.alice);
+            assert_eq!(
+                env.contract.aceptar_admin_interno(),
+                Err(Error::ContratoReportesInvalido)
+            );
+            assert!(!env.contract.es_admin());
+            assert_eq!(env.contract.get_admin_interno(), env.contract_id);
+        }
+
+        #[ink::test]
+        fn probar_get_contrato_reportes() {
+            let mut env = ContractEnv::default();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            // Aún no se estableció el contrato de reportes
+            assert_eq!(env.contract.get_contrato_reportes_interno(), None);
+
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.alice)
+                .unwrap();
+            assert_eq!(
+                env.contract.get_contrato_reportes_interno(),
+                Some(env.accounts.alice)
+            );
+
+            // Cualquier cuenta puede consultarlo
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.get_contrato_reportes_interno(),
+                Some(env.accounts.alice)
+            );
+        }
+
+        #[ink::test]
+        fn probar_contrato_reportes_no_participa() {
+            // Inicializar sistema con usuarios registrados
+            let mut env = ContractEnv::new_inicializado();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(env.contract_id);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+
+            let eleccion_id = env
+                .contract
+                .crear_eleccion_interno(
+                    String::from("Presidente"),
+                    1,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    2,
+                    0,
+                    2,
+                    2,
+                    1970,
+                    0,
+                    true,
+                )
+                .unwrap();
+
+            // Establecer el tiempo del bloque en uno válido para registrarse, 01/01/1970 00:00hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Alice es candidata aprobada y Django votante aprobado
+            for (id, rol) in [
+                (env.accounts.alice, Rol::Candidato),
+                (env.accounts.django, Rol::Votante),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(id);
+                env.contract
+                    .registrar_en_eleccion_interno(eleccion_id, rol.clone())
+                    .unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+                env.contract
+                    .cambiar_estado_aprobacion_interno(
+                        eleccion_id,
+                        id,
+                        rol,
+                        EstadoAprobacion::Aprobado,
+                    )
+                    .unwrap();
+            }
+
+            // Eve, aún no registrada, es el contrato de reportes
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.eve)
                 .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.eve);
+            assert_eq!(
+                env.contract.registrar_usuario_interno(
+                    String::from("Eve"),
+                    String::from("E"),
+                    String::from("55555555")
+                ),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert!(!env.contract.usuario_existe(env.accounts.eve));
+
+            // Django, ya registrado y aprobado, pasa a ser el contrato de reportes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
+            env.contract
+                .establecer_contrato_reportes_interno(env.accounts.django)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.accounts.django);
+            assert_eq!(
+                env.contract.registrar_en_eleccion_interno(eleccion_id, Rol::Candidato),
+                Err(Error::UsuarioNoPermitido)
+            );
+
+            // Establecer el tiempo del bloque en uno válido para votar, 02/02/1970 00:11hs
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(2768460000);
+            assert_eq!(
+                env.contract.votar_interno(eleccion_id, env.accounts.alice, 1),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(
+                env.contract.votar_en_blanco_interno(eleccion_id),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(
+                env.contract.votar_write_in_interno(eleccion_id, env.accounts.charlie),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(
+                env.contract.cambiar_voto_interno(eleccion_id, env.accounts.alice),
+                Err(Error::UsuarioNoPermitido)
+            );
+            assert_eq!(env.contract.he_votado_interno(eleccion_id), Ok(false));
         }
 
         #[ink::test]
@@ -2122,6 +10134,8 @@ mod sistema_votacion {
                     1,
                     1,
                     1970,
+                    0,
+                    true,
                 )
                 .unwrap();
 
@@ -2137,9 +10151,7 @@ mod sistema_votacion {
 
             // Establecer con fines de pruebas el id del contrato reportes igual al administrador
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(env.contract_id);
-            env.contract
-                .establecer_contrato_reportes_interno(env.contract_id)
-                .unwrap();
+            env.contract.establecer_contrato_reportes_sin_validar(env.contract_id);
 
             // Verificar que no puede invocarse con un id de elección inválido
             assert_eq!(
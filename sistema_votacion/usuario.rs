@@ -19,6 +19,11 @@ impl Usuario {
             dni,
         }
     }
+
+    /// Determina si un DNI tiene un formato válido: entre 7 y 8 dígitos numéricos
+    pub fn es_dni_valido(dni: &str) -> bool {
+        (7..=8).contains(&dni.len()) && dni.bytes().all(|c| c.is_ascii_digit())
+    }
 }
 
 #[cfg(test)]
@@ -36,4 +41,16 @@ mod tests {
         let usuario3 = Usuario::new("Carlos".to_string(), "Rodrigues".to_string(), "39_040_417".to_string());
         assert_eq!(usuario3.dni,"39_040_417".to_string());
     }
+
+    #[test]
+    fn probar_es_dni_valido(){
+        assert!(Usuario::es_dni_valido("39040417"));
+        assert!(Usuario::es_dni_valido("9040417"));
+
+        assert!(!Usuario::es_dni_valido(""));
+        assert!(!Usuario::es_dni_valido("904041"));
+        assert!(!Usuario::es_dni_valido("390404170"));
+        assert!(!Usuario::es_dni_valido("39_040_417"));
+        assert!(!Usuario::es_dni_valido("3904041a"));
+    }
 }
\ No newline at end of file
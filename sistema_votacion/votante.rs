@@ -7,10 +7,14 @@ use crate::enums::Error;
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 #[derive(Debug, PartialEq)]
 /// Representa un votante en una eleccion determinada.
-/// Almacena su `AccountId` y si voto o no.
+/// Almacena su `AccountId`, si voto o no, el candidato al que votó, el peso de su voto
+/// y el nonce de la transacción con la que votó.
 pub struct Votante {
     pub(crate) id: AccountId,
     pub(crate) ha_votado: bool,
+    pub(crate) voto_a: Option<AccountId>,
+    pub(crate) peso: u32,
+    pub(crate) nonce: Option<u64>,
 }
 
 //#[ink::trait_definition]
@@ -41,11 +45,14 @@ impl Miembro for Votante {
 
 impl Votante {
     /// Construye un nuevo votante con el `AccountId`.
-    /// Inicializa con `ha_votado` en `false`
+    /// Inicializa con `ha_votado` en `false`, sin candidato votado ni nonce y un voto de peso 1
     pub fn new(id: AccountId) -> Self {
         Self {
             id,
             ha_votado: false,
+            voto_a: None,
+            peso: 1,
+            nonce: None,
         }
     }
 }
@@ -59,6 +66,9 @@ mod tests {
         let votante_id: [u8; 32] = [0; 32];
         let votante = Votante::new(AccountId::from(votante_id));
         assert!(!votante.ha_votado);
+        assert!(votante.voto_a.is_none());
+        assert_eq!(votante.peso, 1);
+        assert!(votante.nonce.is_none());
 
         let votante_id: [u8; 32] = [255; 32];
         let votante = Votante::new(AccountId::from(votante_id));